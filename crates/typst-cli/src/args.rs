@@ -442,6 +442,19 @@ pub struct ProcessArgs {
     #[arg(long = "features", value_delimiter = ',', env = "TYPST_FEATURES")]
     pub features: Vec<Feature>,
 
+    /// The maximum nesting depth of function calls. Defaults to 80.
+    ///
+    /// Raising it far above the default can make deeply recursive documents
+    /// crash with a stack overflow instead of failing with an error.
+    #[clap(long, env = "TYPST_MAX_CALL_DEPTH", value_name = "DEPTH")]
+    pub max_call_depth: Option<usize>,
+
+    /// The maximum nesting depth of show rules. Defaults to 64.
+    ///
+    /// This should stay below the maximum function call depth.
+    #[clap(long, env = "TYPST_MAX_SHOW_RULE_DEPTH", value_name = "DEPTH")]
+    pub max_show_rule_depth: Option<usize>,
+
     /// The format to emit diagnostics in.
     #[clap(long, default_value_t)]
     pub diagnostic_format: DiagnosticFormat,
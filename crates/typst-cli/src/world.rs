@@ -6,6 +6,7 @@ use std::sync::LazyLock;
 
 use ecow::{EcoString, eco_format};
 use typst::diag::{FileError, FileResult};
+use typst::engine::DepthLimits;
 use typst::foundations::{Bytes, Datetime, Dict, Duration, IntoValue, Repr};
 use typst::syntax::{
     FileId, PathError, RootedPath, Source, VirtualPath, VirtualRoot, VirtualizeError,
@@ -64,7 +65,19 @@ impl SystemWorld {
             let features =
                 process_args.features.iter().copied().map(Into::into).collect();
 
-            Library::builder().with_inputs(inputs).with_features(features).build()
+            let mut depth_limits = DepthLimits::default();
+            if let Some(depth) = process_args.max_call_depth {
+                depth_limits = depth_limits.with_call_depth(depth);
+            }
+            if let Some(depth) = process_args.max_show_rule_depth {
+                depth_limits = depth_limits.with_show_rule_depth(depth);
+            }
+
+            Library::builder()
+                .with_inputs(inputs)
+                .with_features(features)
+                .with_depth_limits(depth_limits)
+                .build()
         };
 
         let now = match world_args.creation_timestamp {
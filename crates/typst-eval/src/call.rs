@@ -27,7 +27,10 @@ impl Eval for ast::FuncCall<'_> {
         let span = self.span();
         let callee = self.callee();
 
-        vm.engine.route.check_call_depth().at(span)?;
+        vm.engine
+            .route
+            .check_call_depth(&vm.engine.library.depth_limits)
+            .at(span)?;
 
         // Try to evaluate as a call to an associated function or field.
         if let ast::Expr::FieldAccess(access) = callee {
@@ -94,7 +97,10 @@ fn eval_math_call(vm: &mut Vm, math_call: ast::MathCall) -> SourceResult<Value>
     let callee = math_call.callee();
     let mut target_span = Span::detached();
 
-    vm.engine.route.check_call_depth().at(span)?;
+    vm.engine
+        .route
+        .check_call_depth(&vm.engine.library.depth_limits)
+        .at(span)?;
 
     let math_call_result = match callee {
         ast::MathAccess::MathIdent(ident) => {
@@ -8,8 +8,8 @@ use typst_library::layout::{
 };
 use typst_library::routines::Pair;
 use typst_library::text::{
-    LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TextElem,
-    is_default_ignorable,
+    LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TabElem,
+    TextElem, is_default_ignorable,
 };
 use typst_syntax::Span;
 use typst_utils::SliceExt;
@@ -118,6 +118,8 @@ fn handle(
             Whitespace::Normal => HtmlElement::new(tag::br).spanned(elem.span()).into(),
             Whitespace::Pre => HtmlNode::text("\n", elem.span()),
         });
+    } else if let Some(elem) = child.to_packed::<TabElem>() {
+        converter.push(HtmlNode::text('\t', elem.span()));
     } else if let Some(elem) = child.to_packed::<SmartQuoteElem>() {
         let double = elem.double.get(styles);
         let quote = if elem.enabled.get(styles) {
@@ -13,21 +13,21 @@ use typst_library::introspection::{
     Counter, DocumentIntrospection, Locator, QueryIntrospection,
 };
 use typst_library::layout::resolve::{Cell, CellGrid, Entry, Header};
-use typst_library::layout::{BlockElem, HElem, OuterVAlignment, Sizing};
+use typst_library::layout::{BlockElem, Dir, HElem, OuterVAlignment, Sizing};
 use typst_library::math::EquationElem;
 use typst_library::math::ir::resolve_equation;
 use typst_library::model::{
     Attribution, BibliographyElem, CiteElem, CiteGroup, CslIndentElem, CslLightElem,
-    Destination, DirectLinkElem, DividerElem, EarlyLinkResolver, EmphElem, EnumElem,
-    FigureCaption, FigureElem, FootnoteContainer, FootnoteElem, FootnoteEntry,
-    FootnoteMarker, HeadingElem, LinkElem, LinkTarget, ListElem, OutlineElem,
-    OutlineEntry, OutlineNode, ParElem, ParbreakElem, QuoteElem, RefElem, StrongElem,
-    TableCell, TableElem, TermsElem, TitleElem, Works,
+    Destination, DirectLinkElem, DividerElem, EarlyLinkResolver, EmphElem, EndnoteElem,
+    EndnoteEntry, EndnotesElem, EnumElem, FigureCaption, FigureElem, FootnoteContainer,
+    FootnoteElem, FootnoteEntry, FootnoteMarker, HeadingElem, LinkElem, LinkTarget,
+    ListElem, OutlineElem, OutlineEntry, OutlineNode, ParElem, ParbreakElem, QuoteElem,
+    RefElem, StrongElem, TableCell, TableElem, TermsElem, TitleElem, Works,
 };
 use typst_library::routines::Arenas;
 use typst_library::text::{
-    HighlightElem, LinebreakElem, OverlineElem, RawElem, RawLine, SmallcapsElem,
-    SpaceElem, StrikeElem, SubElem, SuperElem, UnderlineElem,
+    HighlightElem, IsolateElem, LinebreakElem, OverlineElem, RawElem, RawLine, RubyElem,
+    SmallcapsElem, SpaceElem, StrikeElem, SubElem, SuperElem, UnderlineElem,
 };
 use typst_library::visualize::{Color, ImageElem};
 use typst_syntax::Span;
@@ -58,6 +58,9 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Html, FOOTNOTE_MARKER_RULE);
     rules.register(Html, FOOTNOTE_CONTAINER_RULE);
     rules.register(Html, FOOTNOTE_ENTRY_RULE);
+    rules.register(Html, ENDNOTE_RULE);
+    rules.register(Html, ENDNOTES_RULE);
+    rules.register(Html, ENDNOTE_ENTRY_RULE);
     rules.register(Html, OUTLINE_RULE);
     rules.register(Html, OUTLINE_ENTRY_RULE);
     rules.register(Html, REF_RULE);
@@ -76,6 +79,8 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Html, STRIKE_RULE);
     rules.register(Html, HIGHLIGHT_RULE);
     rules.register(Html, SMALLCAPS_RULE);
+    rules.register(Html, RUBY_RULE);
+    rules.register(Html, ISOLATE_RULE);
     rules.register(Html, RAW_RULE);
     rules.register(Html, RAW_LINE_RULE);
 
@@ -422,6 +427,50 @@ const FOOTNOTE_ENTRY_RULE: ShowFn<FootnoteEntry> = |elem, engine, styles| {
     Ok(prefix + body)
 };
 
+const ENDNOTE_RULE: ShowFn<EndnoteElem> = |elem, engine, styles| {
+    // The endnote number that links to the endnote entry.
+    let link = elem.realize(engine, styles)?;
+    let sup = SuperElem::new(link)
+        .pack()
+        .styled(HtmlElem::role.set(Some("doc-noteref".into())))
+        .spanned(elem.span());
+    Ok(HElem::hole().clone() + sup)
+};
+
+const ENDNOTES_RULE: ShowFn<EndnotesElem> = |elem, engine, _| {
+    let items = elem.notes(engine).into_iter().map(|note| {
+        let loc = note.location().unwrap();
+        let span = note.span();
+        HtmlElem::new(tag::li)
+            .with_body(Some(EndnoteEntry::new(note).pack().spanned(span)))
+            .pack()
+            .located(loc.variant(1))
+            .spanned(span)
+    });
+
+    // Like for footnotes, the list is already numbered by the superscripts.
+    let list = HtmlElem::new(tag::ol)
+        .with_css(css::Properties::new().with("list-style-type", "none"))
+        .with_body(Some(Content::sequence(items)))
+        .pack();
+
+    Ok(BlockElem::packed(
+        HtmlElem::new(tag::section)
+            .with_attr(attr::role, "doc-endnotes")
+            .with_body(Some(list))
+            .pack()
+            .spanned(elem.span()),
+    ))
+};
+
+const ENDNOTE_ENTRY_RULE: ShowFn<EndnoteEntry> = |elem, engine, styles| {
+    let (sup, body) = elem.realize(engine, styles)?;
+    let prefix = sup
+        .styled(HtmlElem::role.set(Some("doc-backlink".into())))
+        .spanned(elem.span());
+    Ok(prefix + body)
+};
+
 const OUTLINE_RULE: ShowFn<OutlineElem> = |elem, engine, styles| {
     fn convert_list(list: Vec<OutlineNode>) -> Content {
         // The Digital Publishing ARIA spec also proposed to add
@@ -723,9 +772,32 @@ const SMALLCAPS_RULE: ShowFn<SmallcapsElem> = |elem, _, styles| {
         .pack())
 };
 
-const RAW_RULE: ShowFn<RawElem> = |elem, _, styles| {
+const RUBY_RULE: ShowFn<RubyElem> = |elem, _, _| {
+    let annotation = HtmlElem::new(tag::rt).with_body(Some(elem.annotation.clone()));
+    Ok(HtmlElem::new(tag::ruby)
+        .with_body(Some(Content::sequence([elem.body.clone(), annotation.pack()])))
+        .pack())
+};
+
+const ISOLATE_RULE: ShowFn<IsolateElem> = |elem, _, styles| {
+    let mut bdi = HtmlElem::new(tag::bdi);
+    if let Smart::Custom(dir) = elem.dir.get(styles).0 {
+        bdi = bdi.with_attr(attr::dir, if dir == Dir::RTL { "rtl" } else { "ltr" });
+    }
+    Ok(bdi.with_body(Some(elem.body.clone())).pack())
+};
+
+const RAW_RULE: ShowFn<RawElem> = |elem, engine, styles| {
     let lines = elem.lines.as_deref().unwrap_or_default();
 
+    if elem.block.get(styles) && elem.numbering.get_ref(styles).is_some() {
+        engine.sink.warn(warning!(
+            elem.span(),
+            "raw line numbering was ignored during HTML export";
+            hint: "line numbers are only displayed in paged export";
+        ));
+    }
+
     let mut seq = EcoVec::with_capacity((2 * lines.len()).saturating_sub(1));
     for (i, line) in lines.iter().enumerate() {
         if i != 0 {
@@ -86,6 +86,9 @@ pub struct Page {
     /// The bleed amount to be added on each side of the page. The bleed is not
     /// included in frame.
     pub bleed: Sides<Abs>,
+    /// The amount of space added around the bleed on each side of the page to
+    /// hold printer's marks. Like the bleed, it is not included in the frame.
+    pub slug: Abs,
     /// How the page is filled.
     ///
     /// - When `None`, the background is transparent.
@@ -23,7 +23,7 @@ use typst_library::{Library, World};
 use typst_utils::{LazyHash, Protected, SliceExt};
 
 use super::{FlowMode, layout_multi_block, layout_single_block};
-use crate::inline::ParSituation;
+use crate::inline::{Exclusion, ParSituation};
 use crate::modifiers::layout_and_modify;
 
 /// Collects all elements of the flow into prepared children. These are much
@@ -48,6 +48,7 @@ pub fn collect<'a>(
         expand,
         output: Vec::with_capacity(children.len()),
         par_situation: ParSituation::First,
+        wrap: None,
     }
     .run(mode)
 }
@@ -62,8 +63,27 @@ struct Collector<'a, 'x, 'y> {
     locator: SplitLocator<'a>,
     output: Vec<Child<'a>>,
     par_situation: ParSituation,
+    wrap: Option<WrapArea>,
 }
 
+/// An area created by `place(wrap: true)` that the lines of following
+/// paragraphs flow around.
+#[derive(Debug, Copy, Clone)]
+struct WrapArea {
+    /// The side of the container on which the area lies.
+    side: FixedAlignment,
+    /// The width of the area, including clearance.
+    width: Abs,
+    /// The height of the area that is not yet covered by collected lines.
+    remaining: Abs,
+    /// The location of the placed element that creates the area.
+    place: Location,
+}
+
+/// How often a paragraph beside a wrap area is laid out at most to determine
+/// the number of lines beside the area.
+const MAX_WRAP_ATTEMPTS: usize = 3;
+
 impl<'a> Collector<'a, '_, '_> {
     /// Perform the collection.
     fn run(self, mode: FlowMode) -> SourceResult<Vec<Child<'a>>> {
@@ -91,6 +111,7 @@ impl<'a> Collector<'a, '_, '_> {
             } else if let Some(elem) = child.to_packed::<ColbreakElem>() {
                 self.output.push(Child::Break(elem.weak.get(styles)));
                 self.par_situation = ParSituation::First;
+                self.wrap = None;
             } else if child.is::<PagebreakElem>() {
                 bail!(
                     child.span(), "pagebreaks are not allowed inside of containers";
@@ -148,7 +169,9 @@ impl<'a> Collector<'a, '_, '_> {
     fn v(&mut self, elem: &'a Packed<VElem>, styles: StyleChain<'a>) {
         self.output.push(match elem.amount {
             Spacing::Rel(rel) => {
-                Child::Rel(rel.resolve(styles), elem.weak.get(styles) as u8)
+                let rel = rel.resolve(styles);
+                self.cover_wrap(rel.relative_to(self.base.y));
+                Child::Rel(rel, elem.weak.get(styles) as u8)
             }
             Spacing::Fr(fr) => Child::Fr(fr, elem.weak.get(styles) as u8),
         });
@@ -161,21 +184,53 @@ impl<'a> Collector<'a, '_, '_> {
         elem: &'a Packed<ParElem>,
         styles: StyleChain<'a>,
     ) -> SourceResult<()> {
-        let lines = crate::inline::layout_par(
-            elem,
-            self.engine,
-            self.locator.next(&elem.span()),
-            styles,
-            self.base,
-            self.expand,
-            self.par_situation,
-        )?
-        .into_frames();
-
+        let locator = self.locator.next(&elem.span());
         let spacing = elem.spacing.resolve(styles);
         let leading = elem.leading.resolve(styles);
 
-        self.output.push(Child::Rel(spacing.into(), 4));
+        let lines = match self.wrap {
+            Some(wrap) => {
+                let (lines, exclusion) =
+                    self.wrapped_par(elem, styles, &locator, leading, wrap)?;
+
+                // The lines were shortened assuming that they end up beside
+                // the area. The distributor relayouts them if a region break
+                // separates them from it.
+                self.output.push(Child::Rel(spacing.into(), 4));
+                self.output.push(Child::Wrapped(self.boxed(WrappedChild {
+                    count: lines.len(),
+                    exclusion,
+                    leading,
+                    place: wrap.place,
+                    base: self.base,
+                    expand: self.expand,
+                    situation: self.par_situation,
+                    elem,
+                    styles,
+                    locator,
+                })));
+
+                let height: Abs = lines.iter().map(Frame::height).sum();
+                let gaps = leading * lines.len().saturating_sub(1) as f64;
+                self.cover_wrap(height + gaps + spacing);
+                lines
+            }
+            None => {
+                let lines = crate::inline::layout_par(
+                    elem,
+                    self.engine,
+                    locator,
+                    styles,
+                    self.base,
+                    self.expand,
+                    self.par_situation,
+                    None,
+                )?
+                .into_frames();
+                self.output.push(Child::Rel(spacing.into(), 4));
+                lines
+            }
+        };
 
         self.lines(lines, leading, styles);
 
@@ -185,48 +240,80 @@ impl<'a> Collector<'a, '_, '_> {
         Ok(())
     }
 
+    /// Lays out a paragraph whose first lines flow around a wrap area.
+    ///
+    /// How many lines are beside the area depends on the heights of the lines,
+    /// which are only known after layout. Thus, we lay out the paragraph
+    /// repeatedly until the number of shortened lines matches the number of
+    /// lines that actually end up beside the area.
+    fn wrapped_par(
+        &mut self,
+        elem: &'a Packed<ParElem>,
+        styles: StyleChain<'a>,
+        locator: &Locator<'a>,
+        leading: Abs,
+        wrap: WrapArea,
+    ) -> SourceResult<(Vec<Frame>, Exclusion)> {
+        let mut exclusion = Exclusion {
+            side: wrap.side,
+            width: wrap.width,
+            lines: usize::MAX,
+        };
+
+        let mut lines = vec![];
+        for _ in 0..MAX_WRAP_ATTEMPTS {
+            lines = crate::inline::layout_par(
+                elem,
+                self.engine,
+                locator.relayout(),
+                styles,
+                self.base,
+                self.expand,
+                self.par_situation,
+                Some(exclusion),
+            )?
+            .into_frames();
+
+            // Count the lines whose top edge is above the area's bottom edge.
+            let mut y = Abs::zero();
+            let beside = lines
+                .iter()
+                .take_while(|frame| {
+                    let is_beside = y < wrap.remaining;
+                    y += frame.height() + leading;
+                    is_beside
+                })
+                .count();
+
+            if beside == exclusion.lines.min(lines.len()) {
+                break;
+            }
+
+            exclusion.lines = beside;
+        }
+
+        exclusion.lines = exclusion.lines.min(lines.len());
+        Ok((lines, exclusion))
+    }
+
+    /// Marks a part of the current wrap area as covered by in-flow content of
+    /// the given height.
+    fn cover_wrap(&mut self, height: Abs) {
+        if let Some(wrap) = &mut self.wrap {
+            wrap.remaining -= height;
+            if wrap.remaining <= Abs::zero() {
+                self.wrap = None;
+            }
+        }
+    }
+
     /// Collect laid-out lines.
     fn lines(&mut self, lines: Vec<Frame>, leading: Abs, styles: StyleChain<'a>) {
-        let align = styles.resolve(AlignElem::alignment);
-        let costs = styles.get(TextElem::costs);
-
-        // Determine whether to prevent widow and orphans.
-        let len = lines.len();
-        let prevent_orphans =
-            costs.orphan() > Ratio::zero() && len >= 2 && !lines[1].is_empty();
-        let prevent_widows =
-            costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty();
-        let prevent_all = len == 3 && prevent_orphans && prevent_widows;
-
-        // Store the heights of lines at the edges because we'll potentially
-        // need these later when `lines` is already moved.
-        let height_at = |i| lines.get(i).map(Frame::height).unwrap_or_default();
-        let front_1 = height_at(0);
-        let front_2 = height_at(1);
-        let back_2 = height_at(len.saturating_sub(2));
-        let back_1 = height_at(len.saturating_sub(1));
-
-        for (i, frame) in lines.into_iter().enumerate() {
+        for (i, line) in line_children(lines, leading, styles).enumerate() {
             if i > 0 {
                 self.output.push(Child::Rel(leading.into(), 5));
             }
-
-            // To prevent widows and orphans, we require enough space for
-            // - all lines if it's just three
-            // - the first two lines if we're at the first line
-            // - the last two lines if we're at the second to last line
-            let need = if prevent_all && i == 0 {
-                front_1 + leading + front_2 + leading + back_1
-            } else if prevent_orphans && i == 0 {
-                front_1 + leading + front_2
-            } else if prevent_widows && i >= 2 && i + 2 == len {
-                back_2 + leading + back_1
-            } else {
-                frame.height()
-            };
-
-            self.output
-                .push(Child::Line(self.boxed(LineChild { frame, align, need })));
+            self.output.push(Child::Line(self.boxed(line)));
         }
     }
 
@@ -258,6 +345,7 @@ impl<'a> Collector<'a, '_, '_> {
                 sticky,
                 alone,
                 fr,
+                grid: baseline_grid(styles),
                 elem,
                 styles,
                 locator,
@@ -267,6 +355,7 @@ impl<'a> Collector<'a, '_, '_> {
             self.output.push(Child::Multi(self.boxed(MultiChild {
                 align,
                 sticky,
+                grid: baseline_grid(styles),
                 alone,
                 elem,
                 styles,
@@ -277,6 +366,9 @@ impl<'a> Collector<'a, '_, '_> {
 
         self.output.push(spacing(elem.below.get(styles)));
         self.par_situation = ParSituation::Other;
+
+        // Blocks don't flow around wrapped content, so they end the wrapping.
+        self.wrap = None;
     }
 
     /// Collects a placed element into a [`PlacedChild`].
@@ -292,6 +384,7 @@ impl<'a> Collector<'a, '_, '_> {
         let align_y = alignment.map(|align| align.y().map(|y| y.resolve(styles)));
         let scope = elem.scope.get(styles);
         let float = elem.float.get(styles);
+        let wrap = elem.wrap.get(styles);
 
         match (float, align_y) {
             (true, Smart::Custom(None | Some(FixedAlignment::Center))) => bail!(
@@ -306,6 +399,34 @@ impl<'a> Collector<'a, '_, '_> {
             _ => {}
         }
 
+        if scope == PlacementScope::Page {
+            if float {
+                bail!(elem.span(), "page-scoped placement cannot be floating");
+            }
+            if wrap {
+                bail!(elem.span(), "wrapping is not available for page-scoped placement");
+            }
+
+            // Page-scoped content is laid out by the page run, which finds the
+            // element through its introspection tag.
+            return Ok(());
+        }
+
+        if wrap {
+            if float {
+                bail!(elem.span(), "wrapping is not available for floating placement");
+            }
+            if !matches!(align_y, Smart::Custom(None))
+                || align_x == FixedAlignment::Center
+            {
+                bail!(
+                    elem.span(),
+                    "wrapped content must be aligned to the start or end";
+                    hint: "try `place(left, wrap: true, ..)` or `place(right, wrap: true, ..)`";
+                );
+            }
+        }
+
         if !float && scope == PlacementScope::Parent {
             bail!(
                 elem.span(),
@@ -317,7 +438,7 @@ impl<'a> Collector<'a, '_, '_> {
         let locator = self.locator.next(&elem.span());
         let clearance = elem.clearance.resolve(styles);
         let delta = Axes::new(elem.dx.get(styles), elem.dy.get(styles)).resolve(styles);
-        self.output.push(Child::Placed(self.boxed(PlacedChild {
+        let placed = PlacedChild {
             align_x,
             align_y,
             scope,
@@ -329,7 +450,21 @@ impl<'a> Collector<'a, '_, '_> {
             locator,
             alignment,
             cell: CachedCell::new(),
-        })));
+        };
+
+        // Wrapped content doesn't depend on the concrete regions either, so we
+        // can already lay it out to determine the area to flow around.
+        if wrap {
+            let frame = placed.layout(self.engine, self.base)?;
+            self.wrap = Some(WrapArea {
+                side: align_x,
+                width: frame.width() + clearance,
+                remaining: frame.height() + clearance,
+                place: placed.location(),
+            });
+        }
+
+        self.output.push(Child::Placed(self.boxed(placed)));
 
         Ok(())
     }
@@ -341,6 +476,59 @@ impl<'a> Collector<'a, '_, '_> {
     }
 }
 
+/// Turns the laid-out lines of a paragraph into [`LineChild`]ren.
+fn line_children(
+    lines: Vec<Frame>,
+    leading: Abs,
+    styles: StyleChain,
+) -> impl Iterator<Item = LineChild> {
+    let align = styles.resolve(AlignElem::alignment);
+    let costs = styles.get(TextElem::costs);
+    let grid = baseline_grid(styles);
+
+    // Determine whether to prevent widow and orphans.
+    let len = lines.len();
+    let prevent_orphans =
+        costs.orphan() > Ratio::zero() && len >= 2 && !lines[1].is_empty();
+    let prevent_widows =
+        costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty();
+    let prevent_all = len == 3 && prevent_orphans && prevent_widows;
+
+    // Store the heights of lines at the edges because we'll potentially
+    // need these later when `lines` is already moved.
+    let height_at = |i| lines.get(i).map(Frame::height).unwrap_or_default();
+    let front_1 = height_at(0);
+    let front_2 = height_at(1);
+    let back_2 = height_at(len.saturating_sub(2));
+    let back_1 = height_at(len.saturating_sub(1));
+
+    lines.into_iter().enumerate().map(move |(i, frame)| {
+        // To prevent widows and orphans, we require enough space for
+        // - all lines if it's just three
+        // - the first two lines if we're at the first line
+        // - the last two lines if we're at the second to last line
+        let need = if prevent_all && i == 0 {
+            front_1 + leading + front_2 + leading + back_1
+        } else if prevent_orphans && i == 0 {
+            front_1 + leading + front_2
+        } else if prevent_widows && i >= 2 && i + 2 == len {
+            back_2 + leading + back_1
+        } else {
+            frame.height()
+        };
+
+        LineChild { frame, align, need, grid }
+    })
+}
+
+/// Resolves the baseline grid to which lines and blocks snap, if any.
+fn baseline_grid(styles: StyleChain) -> Option<Abs> {
+    styles
+        .get(ParElem::baseline_grid)
+        .map(|grid| grid.resolve(styles))
+        .filter(|grid| *grid > Abs::zero())
+}
+
 /// A prepared child in flow layout.
 ///
 /// The larger variants are bump-boxed to keep the enum size down.
@@ -354,6 +542,8 @@ pub enum Child<'a> {
     Fr(Fr, u8),
     /// An already layouted line of a paragraph.
     Line(BumpBox<'a, LineChild>),
+    /// Precedes the lines of a paragraph that flow around wrapped content.
+    Wrapped(BumpBox<'a, WrappedChild<'a>>),
     /// An unbreakable block.
     Single(BumpBox<'a, SingleChild<'a>>),
     /// A breakable block.
@@ -367,11 +557,73 @@ pub enum Child<'a> {
 }
 
 /// A child that encapsulates a layouted line of a paragraph.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LineChild {
     pub frame: Frame,
     pub align: Axes<FixedAlignment>,
     pub need: Abs,
+    pub grid: Option<Abs>,
+}
+
+/// A child that precedes the lines of a paragraph whose first lines were
+/// shortened to flow around wrapped content.
+///
+/// The lines are collected assuming that they end up in the same region as
+/// the wrapped content. If a region break separates them from it, the
+/// distributor relayouts the paragraph with fewer shortened lines.
+#[derive(Debug)]
+pub struct WrappedChild<'a> {
+    /// The number of line children that follow.
+    pub count: usize,
+    /// The exclusion the following lines were laid out with.
+    pub exclusion: Exclusion,
+    /// The spacing between the lines.
+    pub leading: Abs,
+    /// The location of the placed element that the lines flow around.
+    pub place: Location,
+    base: Size,
+    expand: bool,
+    situation: ParSituation,
+    elem: &'a Packed<ParElem>,
+    styles: StyleChain<'a>,
+    locator: Locator<'a>,
+}
+
+impl WrappedChild<'_> {
+    /// Lays out the paragraph again, shortening only the given number of
+    /// lines.
+    pub fn relayout(
+        &self,
+        engine: &mut Engine,
+        shortened: usize,
+    ) -> SourceResult<Vec<LineChild>> {
+        let exclusion = Exclusion { lines: shortened, ..self.exclusion };
+        let lines = crate::inline::layout_par(
+            self.elem,
+            engine,
+            self.locator.relayout(),
+            self.styles,
+            self.base,
+            self.expand,
+            self.situation,
+            (shortened > 0).then_some(exclusion),
+        )?
+        .into_frames();
+        Ok(line_children(lines, self.leading, self.styles).collect())
+    }
+}
+
+/// The remaining lines of a paragraph that was relayouted by the distributor
+/// because it was separated from the content it wraps around. Similar to
+/// [`MultiSpill`].
+#[derive(Clone)]
+pub struct LineSpill {
+    /// The lines that are not yet distributed.
+    pub lines: std::vec::IntoIter<LineChild>,
+    /// The spacing between the lines.
+    pub leading: Abs,
+    /// Whether no line was distributed yet.
+    pub first: bool,
 }
 
 /// A child that encapsulates a prepared unbreakable block.
@@ -381,6 +633,7 @@ pub struct SingleChild<'a> {
     pub sticky: bool,
     pub alone: bool,
     pub fr: Option<Fr>,
+    pub grid: Option<Abs>,
     elem: &'a Packed<BlockElem>,
     styles: StyleChain<'a>,
     locator: Locator<'a>,
@@ -446,6 +699,7 @@ fn layout_single_impl(
 pub struct MultiChild<'a> {
     pub align: Axes<FixedAlignment>,
     pub sticky: bool,
+    pub grid: Option<Abs>,
     alone: bool,
     elem: &'a Packed<BlockElem>,
     styles: StyleChain<'a>,
@@ -12,7 +12,8 @@ use typst_library::layout::{
     OuterHAlignment, PlacementScope, Point, Region, Regions, Rel, Size,
 };
 use typst_library::model::{
-    FootnoteElem, FootnoteEntry, LineNumberingScope, Numbering, ParLineMarker,
+    FootnoteElem, FootnoteEntry, LineNumberingScope, MarginNoteElem, Numbering,
+    ParLineMarker,
 };
 use typst_library::pdf::ArtifactKind;
 use typst_syntax::Span;
@@ -44,6 +45,8 @@ pub fn compose(
         config,
         page_base: regions.base(),
         column: 0,
+        column_x: Abs::zero(),
+        margin_notes: vec![],
         page_insertions: Insertions::default(),
         column_insertions: Insertions::default(),
         work,
@@ -66,6 +69,11 @@ pub struct Composer<'a, 'b, 'x, 'y> {
     pub work: &'x mut Work<'a, 'b>,
     pub config: &'x Config<'x>,
     column: usize,
+    /// The horizontal offset of the current column in its page/region.
+    column_x: Abs,
+    /// The horizontal center of each margin that holds margin notes, along
+    /// with the bottom edge of the last note in it.
+    margin_notes: Vec<(Abs, Abs)>,
     page_base: Size,
     page_insertions: Insertions<'a, 'b>,
     column_insertions: Insertions<'a, 'b>,
@@ -92,7 +100,9 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             match self.page_contents(locator.relayout(), pod) {
                 Ok(frame) => break frame,
                 Err(Stop::Finish(_)) => unreachable!(),
-                Err(Stop::Relayout(PlacementScope::Column)) => unreachable!(),
+                Err(Stop::Relayout(PlacementScope::Column | PlacementScope::Page)) => {
+                    unreachable!()
+                }
                 Err(Stop::Relayout(PlacementScope::Parent)) => {
                     *self.work = checkpoint.clone();
                     continue;
@@ -107,8 +117,13 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
 
     /// Lay out the inner contents of a container/page.
     fn page_contents(&mut self, locator: Locator, regions: Regions) -> FlowResult<Frame> {
+        // Margin notes from a previous attempt at laying out this region must
+        // not affect this one.
+        self.margin_notes.clear();
+
         // No point in create column regions, if there's just one!
         if self.config.columns.count == 1 {
+            self.column_x = Abs::zero();
             return self.column(locator, regions);
         }
 
@@ -140,14 +155,7 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
 
         // Lay out the columns and stitch them together.
         for i in 0..self.config.columns.count {
-            self.column = i;
-            let frame = self.column(locator.next(&()), inner)?;
-
-            if !regions.expand.y {
-                output.size_mut().y.set_max(frame.height());
-            }
-
-            let width = frame.width();
+            let width = self.config.columns.width;
             let x = if self.config.columns.dir == Dir::LTR {
                 offset
             } else {
@@ -155,6 +163,14 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             };
             offset += width + self.config.columns.gutter;
 
+            self.column = i;
+            self.column_x = x;
+            let frame = self.column(locator.next(&()), inner)?;
+
+            if !regions.expand.y {
+                output.size_mut().y.set_max(frame.height());
+            }
+
             // During distribution, the baseline of the region is set to the
             // baseline of the first frame - e.g., the first paragraph line.
             // However, this does not propagate outwards unless we also update
@@ -221,6 +237,18 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             )?;
         }
 
+        // Lay out margin notes anchored in this column.
+        if self.config.mode == FlowMode::Root {
+            layout_margin_notes(
+                self.engine,
+                self.config,
+                self.column,
+                self.column_x,
+                &mut self.margin_notes,
+                &mut output,
+            )?;
+        }
+
         Ok(output)
     }
 
@@ -287,6 +315,8 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let base = match placed.scope {
             PlacementScope::Column => regions.base(),
             PlacementScope::Parent => self.page_base,
+            // Page-scoped floats are rejected during collection.
+            PlacementScope::Page => unreachable!(),
         };
 
         // Lay out the placed element.
@@ -304,6 +334,7 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
                     .sum();
                 remaining / self.config.columns.count as f64
             }
+            PlacementScope::Page => unreachable!(),
         };
 
         // We only require clearance if there is other content.
@@ -335,6 +366,7 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let area = match placed.scope {
             PlacementScope::Column => &mut self.column_insertions,
             PlacementScope::Parent => &mut self.page_insertions,
+            PlacementScope::Page => unreachable!(),
         };
 
         // Put the float there.
@@ -924,13 +956,102 @@ fn layout_line_number(
     Ok(frame)
 }
 
+/// Lays out the margin notes anchored in a column's frame.
+///
+/// Each note is placed in the margin such that its first baseline aligns with
+/// the baseline of the line it was called in. Notes that would overlap with
+/// the previous note in the same margin, possibly from another column, are
+/// pushed downwards.
+///
+/// Like footnote entries, notes are laid out with the styles in effect where
+/// they were called and with a locator derived from their location.
+fn layout_margin_notes(
+    engine: &mut Engine,
+    config: &Config,
+    column: usize,
+    column_x: Abs,
+    margins: &mut Vec<(Abs, Abs)>,
+    output: &mut Frame,
+) -> SourceResult<()> {
+    let mut notes = find_in_frame::<MarginNoteElem>(output);
+    if notes.is_empty() {
+        return Ok(());
+    }
+
+    // Notes must be sorted by height so that collision avoidance below only
+    // has to look at the previous note in the same margin.
+    notes.sort_by_key(|&(y, _)| y);
+
+    for (y, note) in notes {
+        let styles = match &note.styles {
+            Some(local) => config.shared.chain(local),
+            None => config.shared,
+        };
+
+        // As for line numbers, the last column always places its notes in the
+        // end margin and all other columns in the start margin.
+        let margin = if config.columns.count >= 2 {
+            if column + 1 == config.columns.count {
+                OuterHAlignment::End
+            } else {
+                OuterHAlignment::Start
+            }
+        } else {
+            note.side.get(styles)
+        }
+        .resolve(styles);
+
+        let width = note.width.resolve(styles);
+        let clearance = note.clearance.resolve(styles);
+        let gap = note.gap.resolve(styles);
+
+        let frame = crate::layout_frame(
+            engine,
+            &note.body,
+            Locator::synthesize(note.location().unwrap()),
+            styles,
+            Region::new(Size::new(width, Abs::inf()), Axes::new(true, false)),
+        )?;
+
+        let x = match margin {
+            FixedAlignment::Start => -width - clearance,
+            FixedAlignment::End => output.width() + clearance,
+            // Can't happen due to `OuterHAlignment`.
+            FixedAlignment::Center => unreachable!(),
+        };
+
+        // Align the first baseline of the note with the anchor's baseline and
+        // push it down if it would collide with the previous note in the same
+        // margin. Margins are identified by their center relative to the whole
+        // region, such that the gutter between two columns is a single margin.
+        let baseline = if frame.has_baseline() { frame.baseline() } else { Abs::zero() };
+        let half_gutter = config.columns.gutter / 2.0;
+        let center = match margin {
+            FixedAlignment::End => column_x + output.width() + half_gutter,
+            _ => column_x - half_gutter,
+        };
+        let mut top = y - baseline;
+        match margins.iter_mut().find(|(x, _)| x.approx_eq(center)) {
+            Some((_, bottom)) => {
+                top.set_max(*bottom + gap);
+                *bottom = top + frame.height();
+            }
+            None => margins.push((center, top + frame.height())),
+        }
+
+        output.push_frame(Point::new(x, top), frame);
+    }
+
+    Ok(())
+}
+
 /// Collect all matching elements and their vertical positions in the frame.
 ///
 /// On each subframe we encounter, we add that subframe's position to `prev_y`,
 /// until we reach a tag, at which point we add the tag's position and finish.
 /// That gives us the absolute height of the tag from the start of the root
 /// frame.
-fn find_in_frame<T: NativeElement>(frame: &Frame) -> Vec<(Abs, Packed<T>)> {
+pub fn find_in_frame<T: NativeElement>(frame: &Frame) -> Vec<(Abs, Packed<T>)> {
     let mut output = vec![];
     find_in_frame_impl(&mut output, frame, Abs::zero());
     output
@@ -5,8 +5,8 @@ use typst_library::layout::{
 use typst_utils::Numeric;
 
 use super::{
-    Child, Composer, FlowResult, LineChild, MultiChild, MultiSpill, PlacedChild,
-    SingleChild, Stop, Work,
+    Child, Composer, FlowMode, FlowResult, LineChild, LineSpill, MultiChild, MultiSpill,
+    PlacedChild, SingleChild, Stop, Work, WrappedChild,
 };
 
 /// Distributes as many children as fit from `composer.work` into the first
@@ -105,16 +105,24 @@ impl Item<'_, '_> {
 impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     /// Distributes content into the region.
     fn run(&mut self) -> FlowResult<()> {
-        // First, handle spill of a breakable block.
+        // First, handle spill of a breakable block or a relayouted paragraph.
         if let Some(spill) = self.composer.work.spill.take() {
             self.multi_spill(spill)?;
         }
+        if let Some(spill) = self.composer.work.lines.take() {
+            self.line_spill(spill)?;
+        }
 
         // If spill are taken care of, process children until no space is left
         // or no children are left.
         while let Some(child) = self.composer.work.head() {
             self.child(child)?;
             self.composer.work.advance();
+
+            // A relayouted paragraph replaces the collected lines.
+            if let Some(spill) = self.composer.work.lines.take() {
+                self.line_spill(spill)?;
+            }
         }
 
         Ok(())
@@ -133,6 +141,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             Child::Rel(amount, weakness) => self.rel(*amount, *weakness),
             Child::Fr(fr, weakness) => self.fr(*fr, *weakness),
             Child::Line(line) => self.line(line)?,
+            Child::Wrapped(wrapped) => self.wrapped(wrapped)?,
             Child::Single(single) => self.single(single)?,
             Child::Multi(multi) => self.multi(multi)?,
             Child::Placed(placed) => self.placed(placed)?,
@@ -271,10 +280,16 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     }
 
     /// Processes a line of a paragraph.
-    fn line(&mut self, line: &'b LineChild) -> FlowResult<()> {
+    fn line(&mut self, line: &LineChild) -> FlowResult<()> {
+        // Determine how far the line must move down for its baseline to snap
+        // to the baseline grid.
+        let snap = self.baseline_snap(line.grid, line.frame.baseline());
+
         // If the line doesn't fit and a followup region may improve things,
         // finish the region.
-        if !self.regions.size.y.fits(line.frame.height()) && self.regions.may_progress() {
+        if !self.regions.size.y.fits(snap + line.frame.height())
+            && self.regions.may_progress()
+        {
             return Err(Stop::Finish(false));
         }
 
@@ -292,9 +307,127 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
+        self.snap(snap);
         self.frame(line.frame.clone(), line.align, false, false)
     }
 
+    /// Processes the start of a paragraph whose first lines were shortened to
+    /// flow around wrapped content.
+    ///
+    /// The lines were collected assuming that all shortened lines end up
+    /// beside the content. If a region break gets in between, the paragraph
+    /// is laid out again so that the lines in later regions use the full
+    /// width.
+    fn wrapped(&mut self, wrapped: &'b WrappedChild<'a>) -> FlowResult<()> {
+        let shortened = wrapped.exclusion.lines;
+        if shortened == 0 || !self.regions.may_progress() {
+            return Ok(());
+        }
+
+        // Determine how many of the shortened lines end up beside the content,
+        // which is only possible if the content is in this region.
+        let mut beside = 0;
+        if self.items.iter().any(|item| {
+            matches!(item, Item::Placed(_, placed) if placed.location() == wrapped.place)
+        }) {
+            let mut available = self.regions.size.y;
+            let lines = self.composer.work.children[1..].iter().filter_map(|child| {
+                if let Child::Line(line) = child { Some(line) } else { None }
+            });
+
+            for (i, line) in lines.take(shortened).enumerate() {
+                if i > 0 {
+                    available -= wrapped.leading;
+                }
+                let need = if i == 0 { line.need } else { line.frame.height() };
+                if !available.fits(need) {
+                    break;
+                }
+                available -= line.frame.height();
+                beside += 1;
+            }
+        }
+
+        if beside == shortened {
+            return Ok(());
+        }
+
+        // Skip the collected lines and the spacing between them, such that the
+        // last line is the head that is skipped after this child.
+        let lines = wrapped.relayout(self.composer.engine, beside)?;
+        let work = &mut *self.composer.work;
+        work.children = &work.children[2 * wrapped.count - 1..];
+        work.lines = Some(LineSpill {
+            lines: lines.into_iter(),
+            leading: wrapped.leading,
+            first: true,
+        });
+
+        Ok(())
+    }
+
+    /// Processes the lines of a relayouted paragraph.
+    fn line_spill(&mut self, mut spill: LineSpill) -> FlowResult<()> {
+        while let Some(line) = spill.lines.as_slice().first().cloned() {
+            if !spill.first {
+                self.rel(spill.leading.into(), 5);
+            }
+
+            // If the line doesn't fit, keep it and the remaining lines for the
+            // next region.
+            if let Err(stop) = self.line(&line) {
+                self.composer.work.lines = Some(spill);
+                return Err(stop);
+            }
+
+            spill.lines.next();
+            spill.first = false;
+        }
+
+        Ok(())
+    }
+
+    /// The amount of space to insert before a line or block so that the given
+    /// baseline within it lands on the next grid line of the baseline grid.
+    ///
+    /// The grid is measured from the top of the region and only applies in
+    /// the root flow, as the position of nested flows on the page is unknown.
+    fn baseline_snap(&self, grid: Option<Abs>, baseline: Abs) -> Abs {
+        let Some(grid) = grid else { return Abs::zero() };
+        if self.composer.config.mode != FlowMode::Root {
+            return Abs::zero();
+        }
+
+        // In regions of infinite height, like on pages with automatic height,
+        // the used space can't be derived from the remaining space.
+        let used = if self.regions.full.is_finite() {
+            self.regions.full - self.regions.size.y
+        } else {
+            self.items
+                .iter()
+                .map(|item| match item {
+                    Item::Abs(amount, _) => *amount,
+                    Item::Frame(frame, _) => frame.height(),
+                    _ => Abs::zero(),
+                })
+                .sum()
+        };
+
+        let baseline = used + baseline;
+        let snap = grid * (baseline / grid).ceil() - baseline;
+
+        // Avoid moving a full grid step due to floating-point imprecision.
+        if snap.approx_eq(grid) || snap < Abs::zero() { Abs::zero() } else { snap }
+    }
+
+    /// Inserts the space determined by [`baseline_snap`](Self::baseline_snap).
+    fn snap(&mut self, amount: Abs) {
+        if !amount.is_zero() {
+            self.regions.size.y -= amount;
+            self.items.push(Item::Abs(amount, 0));
+        }
+    }
+
     /// Processes an unbreakable block.
     fn single(&mut self, single: &'b SingleChild<'a>) -> FlowResult<()> {
         // Lay out the block.
@@ -312,12 +445,19 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Ok(());
         }
 
+        // Determine how far the block must move down for its first baseline,
+        // or its top edge if it has none, to snap to the baseline grid.
+        let baseline = if frame.has_baseline() { frame.baseline() } else { Abs::zero() };
+        let snap = self.baseline_snap(single.grid, baseline);
+
         // If the block doesn't fit and a followup region may improve things,
         // finish the region.
-        if !self.regions.size.y.fits(frame.height()) && self.regions.may_progress() {
+        if !self.regions.size.y.fits(snap + frame.height()) && self.regions.may_progress()
+        {
             return Err(Stop::Finish(false));
         }
 
+        self.snap(snap);
         self.frame(frame, single.align, single.sticky, false)
     }
 
@@ -330,7 +470,19 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         }
 
         // Lay out the block.
-        let (frame, spill) = multi.layout(self.composer.engine, self.regions)?;
+        let (mut frame, mut spill) = multi.layout(self.composer.engine, self.regions)?;
+
+        // Determine how far the block must move down for its first baseline to
+        // snap to the baseline grid. As this reduces the available space, the
+        // block must then be laid out again.
+        let baseline = if frame.has_baseline() { frame.baseline() } else { Abs::zero() };
+        let snap = self.baseline_snap(multi.grid, baseline);
+        if !snap.is_zero() {
+            let mut regions = self.regions;
+            regions.size.y -= snap;
+            (frame, spill) = multi.layout(self.composer.engine, regions)?;
+        }
+
         if frame.is_empty()
             && spill.as_ref().is_some_and(|s| s.exist_non_empty_frame)
             && self.regions.may_progress()
@@ -341,6 +493,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
+        self.snap(snap);
         self.frame(frame, multi.align, multi.sticky, true)?;
 
         // If the block didn't fully fit into the current region, save it into
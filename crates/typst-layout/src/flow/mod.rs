@@ -6,6 +6,7 @@ mod compose;
 mod distribute;
 
 pub(crate) use self::block::unbreakable_pod;
+pub(crate) use self::compose::find_in_frame;
 
 use std::num::NonZeroUsize;
 use std::rc::Rc;
@@ -33,7 +34,8 @@ use typst_utils::{LazyHash, NonZeroExt, Numeric, Protected};
 
 use self::block::{layout_multi_block, layout_single_block};
 use self::collect::{
-    Child, LineChild, MultiChild, MultiSpill, PlacedChild, SingleChild, collect,
+    Child, LineChild, LineSpill, MultiChild, MultiSpill, PlacedChild, SingleChild,
+    WrappedChild, collect,
 };
 use self::compose::{Composer, compose};
 use self::distribute::distribute;
@@ -169,7 +171,7 @@ fn layout_fragment_impl(
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum FlowMode {
     /// A root flow with block-level elements. Like `FlowMode::Block`, but can
-    /// additionally host footnotes and line numbers.
+    /// additionally host footnotes, line numbers, and margin notes.
     Root,
     /// A flow whose children are block-level elements.
     Block,
@@ -296,6 +298,8 @@ struct Work<'a, 'b> {
     children: &'b [Child<'a>],
     /// Leftovers from a breakable block.
     spill: Option<MultiSpill<'a, 'b>>,
+    /// Lines of a relayouted paragraph that replace its collected lines.
+    lines: Option<LineSpill>,
     /// Queued floats that didn't fit in previous regions.
     floats: EcoVec<&'b PlacedChild<'a>>,
     /// Queued footnotes that didn't fit in previous regions.
@@ -316,6 +320,7 @@ impl<'a, 'b> Work<'a, 'b> {
         Self {
             children,
             spill: None,
+            lines: None,
             floats: EcoVec::new(),
             footnotes: EcoVec::new(),
             footnote_spill: None,
@@ -338,6 +343,7 @@ impl<'a, 'b> Work<'a, 'b> {
     fn done(&self) -> bool {
         self.children.is_empty()
             && self.spill.is_none()
+            && self.lines.is_none()
             && self.floats.is_empty()
             && self.footnote_spill.is_none()
             && self.footnotes.is_empty()
@@ -354,8 +360,8 @@ impl<'a, 'b> Work<'a, 'b> {
 
 /// Shared configuration for the whole flow.
 struct Config<'x> {
-    /// Whether this is the root flow, which can host footnotes and line
-    /// numbers.
+    /// Whether this is the root flow, which can host footnotes, line numbers,
+    /// and margin notes.
     mode: FlowMode,
     /// The styles shared by the whole flow. This is used for footnotes and line
     /// numbers.
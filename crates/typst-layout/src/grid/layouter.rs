@@ -987,6 +987,7 @@ impl<'a> GridLayouter<'a> {
                 continue;
             }
 
+            let (min, max) = self.grid.col_bounds(x, self.styles, self.regions.base().x);
             let mut resolved = Abs::zero();
             for y in 0..self.grid.rows.len() {
                 // We get the parent cell in case this is a merged position.
@@ -1075,7 +1076,14 @@ impl<'a> GridLayouter<'a> {
                 // cell if it spans all fractional columns in a finite region.
                 let already_covered_width = self.cell_spanned_width(cell, parent.x);
 
-                let size = Size::new(available, height);
+                // A maximum width limits the space available to the cell so
+                // that its content wraps instead of widening the column.
+                let width = if max.is_finite() {
+                    available.min(max + already_covered_width)
+                } else {
+                    available
+                };
+                let size = Size::new(width, height);
                 let pod = Region::new(size, Axes::splat(false));
                 let locator = self.cell_locator(parent, 0);
                 let frame = layout_cell(
@@ -1090,6 +1098,7 @@ impl<'a> GridLayouter<'a> {
                 resolved.set_max(frame.width() - already_covered_width);
             }
 
+            resolved = resolved.min(max).max(min);
             self.rcols[x] = resolved;
             auto += resolved;
             count += 1;
@@ -1136,10 +1145,13 @@ impl<'a> GridLayouter<'a> {
             }
         }
 
-        // Redistribute space fairly among overlarge columns.
-        for (&col, rcol) in self.grid.cols.iter().zip(&mut self.rcols) {
+        // Redistribute space fairly among overlarge columns, but never shrink
+        // a column below its minimum width.
+        let base = self.regions.base().x;
+        for (x, (&col, rcol)) in self.grid.cols.iter().zip(&mut self.rcols).enumerate() {
             if col == Sizing::Auto && *rcol > fair {
-                *rcol = fair;
+                let (min, _) = self.grid.col_bounds(x, self.styles, base);
+                *rcol = fair.max(min);
             }
         }
     }
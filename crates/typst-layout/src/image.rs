@@ -1,10 +1,8 @@
-use typst_library::diag::SourceResult;
+use typst_library::diag::{SourceResult, bail};
 use typst_library::engine::Engine;
-use typst_library::foundations::{Packed, StyleChain};
+use typst_library::foundations::{Packed, Resolve, StyleChain};
 use typst_library::introspection::Locator;
-use typst_library::layout::{
-    Abs, Axes, FixedAlignment, Frame, FrameItem, Point, Region, Size,
-};
+use typst_library::layout::{Abs, Axes, Frame, FrameItem, Point, Region, Size};
 use typst_library::visualize::{Curve, Image, ImageElem, ImageFit};
 
 /// Layout the image.
@@ -18,9 +16,16 @@ pub fn layout_image(
 ) -> SourceResult<Frame> {
     let image = elem.decode(engine, styles)?;
 
-    // Determine the image's pixel aspect ratio.
-    let pxw = image.width();
-    let pxh = image.height();
+    // Determine which fraction of the image remains visible after cropping.
+    let crop = elem.crop.get(styles).map(|v| v.unwrap_or_default().get());
+    let visible = Axes::new(1.0 - crop.left - crop.right, 1.0 - crop.top - crop.bottom);
+    if crop.iter().any(|&v| v < 0.0) || visible.x <= 0.0 || visible.y <= 0.0 {
+        bail!(elem.span(), "image crop must leave part of the image visible");
+    }
+
+    // Determine the visible part's pixel aspect ratio.
+    let pxw = image.width() * visible.x;
+    let pxh = image.height() * visible.y;
     let px_ratio = pxw / pxh;
 
     // Determine the region's aspect ratio.
@@ -65,12 +70,17 @@ pub fn layout_image(
         ImageFit::Stretch => target,
     };
 
-    // First, place the image in a frame of exactly its size and then resize
-    // the frame to the target size, center aligning the image in the
-    // process.
+    // First, place the visible part of the image in a frame of exactly its
+    // size and then resize the frame to the target size, aligning the image
+    // at its focus in the process.
     let mut frame = Frame::soft(fitted);
-    frame.push(Point::zero(), FrameItem::Image(image, fitted, elem.span()));
-    frame.resize(target, Axes::splat(FixedAlignment::Center));
+    let full = Size::new(fitted.x / visible.x, fitted.y / visible.y);
+    let offset = Point::new(-full.x * crop.left, -full.y * crop.top);
+    frame.push(offset, FrameItem::Image(image, full, elem.span()));
+    if full != fitted {
+        frame.clip(Curve::rect(fitted));
+    }
+    frame.resize(target, elem.focus.resolve(styles));
 
     // Create a clipping group if only part of the image should be visible.
     if fit == ImageFit::Cover && !target.fits(fitted) {
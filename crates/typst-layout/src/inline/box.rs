@@ -9,7 +9,7 @@ use typst_library::visualize::Stroke;
 use typst_utils::Numeric;
 
 use crate::flow::unbreakable_pod;
-use crate::shapes::{clip_rect, fill_and_stroke};
+use crate::shapes::{clip_rect, fill_and_stroke, shadow_rect};
 
 /// Lay out a box as part of inline layout.
 #[typst_macros::time(name = "box", span = elem.span())]
@@ -57,7 +57,8 @@ pub fn layout_box(
         .unwrap_or_default()
         .map(|s| s.map(Stroke::unwrap_or_default));
 
-    // Only fetch these if necessary (for clipping or filling/stroking).
+    // Only fetch these if necessary (for clipping, filling/stroking, or
+    // shadows).
     let outset = LazyCell::new(|| elem.outset.resolve(styles).unwrap_or_default());
     let radius = LazyCell::new(|| elem.radius.resolve(styles).unwrap_or_default());
 
@@ -71,6 +72,11 @@ pub fn layout_box(
         fill_and_stroke(&mut frame, fill, &stroke, &outset, &radius, elem.span());
     }
 
+    // Add the shadow behind everything else.
+    if let Some(shadow) = elem.shadow.get_ref(styles) {
+        shadow_rect(&mut frame, shadow, &outset, &radius, styles, elem.span());
+    }
+
     // Assign label to the frame.
     if let Some(label) = elem.label() {
         frame.label(label);
@@ -2,12 +2,14 @@ use typst_library::diag::warning;
 use typst_library::foundations::{Packed, Resolve};
 use typst_library::introspection::{SplitLocator, Tag, TagElem};
 use typst_library::layout::{
-    Abs, BoxElem, Dir, Fr, Frame, HElem, InlineElem, InlineItem, Sizing, Spacing,
+    Abs, Axes, BoxElem, Dir, Fr, Frame, HElem, InlineElem, InlineItem, Region, Sizing,
+    Spacing,
 };
+use typst_library::model::TabAlignment;
 use typst_library::routines::Pair;
 use typst_library::text::{
-    LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TextElem,
-    is_default_ignorable,
+    LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TabElem,
+    TextElem, is_default_ignorable,
 };
 use typst_syntax::Span;
 use typst_utils::Numeric;
@@ -38,6 +40,9 @@ pub enum Item<'a> {
     Fractional(Fr, Option<(&'a Packed<BoxElem>, Locator<'a>, StyleChain<'a>)>),
     /// Layouted inline-level content.
     Frame(Frame),
+    /// A tab that advances to the first of the given stops beyond its
+    /// position. Resolved to spacing when building a line.
+    Tab(Vec<ResolvedTabStop>),
     /// A tag.
     Tag(&'a Tag),
     /// An item that is invisible and needs to be skipped, e.g. a Unicode
@@ -72,7 +77,9 @@ impl<'a> Item<'a> {
     pub fn textual(&self) -> &str {
         match self {
             Self::Text(shaped) => shaped.text,
-            Self::Absolute(_, _) | Self::Fractional(_, _) => SPACING_REPLACE,
+            Self::Absolute(_, _) | Self::Fractional(_, _) | Self::Tab(_) => {
+                SPACING_REPLACE
+            }
             Self::Frame(_) => OBJ_REPLACE,
             Self::Tag(_) => "",
             Self::Skip(s) => s,
@@ -90,12 +97,23 @@ impl<'a> Item<'a> {
             Self::Text(shaped) => shaped.width(),
             Self::Absolute(v, _) => *v,
             Self::Frame(frame) => frame.width(),
-            Self::Fractional(_, _) | Self::Tag(_) => Abs::zero(),
+            Self::Fractional(_, _) | Self::Tab(_) | Self::Tag(_) => Abs::zero(),
             Self::Skip(_) => Abs::zero(),
         }
     }
 }
 
+/// A tab stop with resolved position and laid out leader.
+#[derive(Debug)]
+pub struct ResolvedTabStop {
+    /// The distance of the stop from the start of the line.
+    pub pos: Abs,
+    /// How the content following the tab is aligned at the stop.
+    pub align: TabAlignment,
+    /// A single repetition of the leader that fills the gap before the stop.
+    pub leader: Option<Frame>,
+}
+
 /// An item or not-yet shaped text. We can't shape text until we have collected
 /// all items because only then we can compute BiDi, and we need to split shape
 /// runs at level boundaries.
@@ -187,6 +205,26 @@ pub fn collect<'a>(
                 if elem.justify.get(styles) { "\u{2028}" } else { "\n" },
                 styles,
             );
+        } else if let Some(elem) = child.to_packed::<TabElem>() {
+            let mut stops = Vec::with_capacity(config.tab_stops.len());
+            for stop in &config.tab_stops {
+                let leader = match &stop.leader {
+                    Some(leader) => Some(crate::layout_frame(
+                        engine,
+                        leader,
+                        locator.next(&elem.span()),
+                        styles,
+                        Region::new(region, Axes::splat(false)),
+                    )?),
+                    None => None,
+                };
+                stops.push(ResolvedTabStop {
+                    pos: stop.pos.resolve(styles),
+                    align: stop.align,
+                    leader,
+                });
+            }
+            collector.push_item(Item::Tab(stops));
         } else if let Some(elem) = child.to_packed::<SmartQuoteElem>() {
             let double = elem.double.get(styles);
             if elem.enabled.get(styles) {
@@ -20,7 +20,15 @@ pub fn finalize(
     {
         region.x.min(
             p.config.hanging_indent
-                + lines.iter().map(|line| line.width).max().unwrap_or_default(),
+                + lines
+                    .iter()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        let (left, right) = p.config.excluded(i);
+                        left + line.width + right
+                    })
+                    .max()
+                    .unwrap_or_default(),
         )
     } else {
         region.x
@@ -29,7 +37,8 @@ pub fn finalize(
     // Stack the lines into one frame per region.
     lines
         .iter()
-        .map(|line| commit(engine, p, line, width, region.y, locator))
+        .enumerate()
+        .map(|(i, line)| commit(engine, p, line, i, width, region.y, locator))
         .collect::<SourceResult<_>>()
         .map(Fragment::frames)
 }
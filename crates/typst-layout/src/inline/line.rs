@@ -1,10 +1,11 @@
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Deref, DerefMut};
 
+use either::Either;
 use typst_library::engine::Engine;
 use typst_library::introspection::{SplitLocator, Tag, TagFlags};
-use typst_library::layout::{Abs, Dir, Em, Fr, Frame, FrameItem, Point};
-use typst_library::model::ParLineMarker;
+use typst_library::layout::{Abs, Dir, Em, FixAlignment, Fr, Frame, FrameItem, Point};
+use typst_library::model::{ParLineMarker, TabAlignment};
 use typst_library::text::{Lang, TextElem, families, variant};
 use typst_utils::Numeric;
 
@@ -123,12 +124,15 @@ pub enum Dash {
 }
 
 /// Create a line which spans the given range.
+///
+/// The `index` is the position of the line within the paragraph.
 pub fn line<'a>(
     engine: &Engine,
     p: &'a Preparation,
     range: Range,
     breakpoint: Breakpoint,
     pred: Option<&Line<'a>>,
+    index: usize,
 ) -> Line<'a> {
     // The line's full text.
     let full = &p.text[range.clone()];
@@ -187,6 +191,9 @@ pub fn line<'a>(
     // Deal with stretchability of glyphs at the end of the line.
     adjust_glyph_stretch_at_line_end(p, &mut items);
 
+    // Advance tabs to their tab stops.
+    resolve_tabs(p, &mut items, index);
+
     // Compute the line's width.
     let width = items.iter().map(Item::natural_width).sum();
 
@@ -244,6 +251,114 @@ fn trim_weak_spacing(items: &mut Items) {
     }
 }
 
+/// Replaces the tabs in the line with spacing (or leaders) that advances the
+/// following content to the next tab stop.
+///
+/// Tab stops are measured from the start edge of the paragraph, so they are
+/// mirrored in right-to-left paragraphs.
+fn resolve_tabs(p: &Preparation, items: &mut Items, index: usize) {
+    let dir = p.config.dir;
+
+    // All lines but the first are shifted by the hanging indent, while the
+    // first line contains negative spacing that compensates for it. An
+    // exclusion on the start side shifts the line further.
+    let (left, right) = p.config.excluded(index);
+    let mut x = p.config.hanging_indent + if dir == Dir::LTR { left } else { right };
+    for i in 0..items.len() {
+        let Item::Tab(stops) = &*items[i].1 else {
+            x += items[i].1.natural_width();
+            continue;
+        };
+
+        let Some(stop) = stops.iter().find(|stop| stop.pos > x) else {
+            continue;
+        };
+
+        // The content up to the next tab is aligned at the stop.
+        let following = items[i + 1..]
+            .iter()
+            .map(|(_, item)| &**item)
+            .take_while(|item| !matches!(item, Item::Tab(_)));
+        let shift = match stop.align {
+            TabAlignment::Align(align) => {
+                // Positions are measured from the start edge, so the physical
+                // alignment is flipped in right-to-left paragraphs.
+                let fixed = align.fix(dir);
+                let fixed = if dir == Dir::LTR { fixed } else { fixed.inv() };
+                fixed.position(following.map(Item::natural_width).sum())
+            }
+            TabAlignment::Decimal => decimal_offset(following, dir),
+        };
+
+        let amount = (stop.pos - x - shift).max(Abs::zero());
+        let item = match &stop.leader {
+            Some(piece) => Item::Frame(leader(piece, x, amount, dir)),
+            None => Item::Absolute(amount, false),
+        };
+
+        items[i].1 = ItemEntry::Box(Box::new(item));
+        x += amount;
+    }
+}
+
+/// The width of the given items up to their first decimal point, measured
+/// from the start edge.
+fn decimal_offset<'a, 'b: 'a>(
+    items: impl Iterator<Item = &'a Item<'b>>,
+    dir: Dir,
+) -> Abs {
+    let mut offset = Abs::zero();
+    for item in items {
+        let Item::Text(shaped) = item else {
+            offset += item.natural_width();
+            continue;
+        };
+
+        // The glyphs are in visual order, so in a right-to-left paragraph,
+        // the start edge is at the last one.
+        let glyphs = if dir == Dir::LTR {
+            Either::Left(shaped.glyphs.iter())
+        } else {
+            Either::Right(shaped.glyphs.iter().rev())
+        };
+
+        for glyph in glyphs {
+            if glyph.c == '.' {
+                return offset;
+            }
+            offset += glyph.x_advance.at(glyph.size);
+        }
+    }
+    offset
+}
+
+/// Fills the given width starting at `x` with repetitions of a leader piece.
+///
+/// The pieces are placed on a grid anchored at the start edge of the
+/// paragraph so that leaders in consecutive lines line up. In a right-to-left
+/// paragraph, `x` is measured from the right.
+fn leader(piece: &Frame, x: Abs, width: Abs, dir: Dir) -> Frame {
+    let mut frame = Frame::soft(Size::new(width, piece.height()));
+    if piece.has_baseline() {
+        frame.set_baseline(piece.baseline());
+    }
+
+    let step = piece.width();
+    if step <= Abs::zero() {
+        return frame;
+    }
+
+    let mut start = step * (x / step).ceil();
+    while start + step <= x + width {
+        let offset = start - x;
+        let pos = if dir == Dir::LTR { offset } else { width - offset - step };
+        frame.push_frame(Point::with_x(pos), piece.clone());
+        start += step;
+    }
+
+    frame
+}
+
 /// Calls `f` for the BiDi-reordered ranges of a line.
 fn reorder<F>(p: &Preparation, range: Range, mut f: F)
 where
@@ -483,17 +598,22 @@ pub fn apply_shift<'a>(
 }
 
 /// Commit to a line and build its frame.
+///
+/// The `index` is the index of the line within the paragraph.
 #[allow(clippy::too_many_arguments)]
 pub fn commit(
     engine: &mut Engine,
     p: &Preparation,
     line: &Line,
+    index: usize,
     width: Abs,
     full: Abs,
     locator: &mut SplitLocator<'_>,
 ) -> SourceResult<Frame> {
-    let mut remaining = width - line.width - p.config.hanging_indent;
-    let mut offset = Abs::zero();
+    // An exclusion beside the line takes away space on one of its sides.
+    let (left, right) = p.config.excluded(index);
+    let mut remaining = width - line.width - p.config.hanging_indent - left - right;
+    let mut offset = left;
 
     // We always build the line from left to right. In an LTR paragraph, we must
     // thus add the hanging indent to the offset. In an RTL paragraph, the
@@ -603,7 +723,7 @@ pub fn commit(
                 frame.push(Point::zero(), FrameItem::Tag((*tag).clone()));
                 frames.push((offset, frame, idx));
             }
-            Item::Skip(_) => {}
+            Item::Tab(_) | Item::Skip(_) => {}
         }
     }
 
@@ -2,6 +2,7 @@ use std::ops::{Add, Sub};
 use std::sync::LazyLock;
 
 use az::SaturatingAs;
+use either::Either;
 use icu_properties::CodePointMapDataBorrowed;
 use icu_properties::props::LineBreak;
 use icu_provider_blob::BlobDataProvider;
@@ -173,25 +174,34 @@ fn linebreak_simple<'a>(
     let mut start = 0;
     let mut last = None;
 
+    // The width available to the line with the given index.
+    let available = |index| {
+        let (left, right) = p.config.excluded(index);
+        width - left - right
+    };
+
     breakpoints(p, |end, breakpoint| {
         // Compute the line and its size.
-        let mut attempt = line(engine, p, start..end, breakpoint, lines.last());
+        let mut attempt =
+            line(engine, p, start..end, breakpoint, lines.last(), lines.len());
 
         // If the line doesn't fit anymore, we push the last fitting attempt
         // into the stack and rebuild the line from the attempt's end. The
         // resulting line cannot be broken up further.
-        if !width.fits(attempt.width)
+        if !available(lines.len()).fits(attempt.width)
             && let Some((last_attempt, last_end)) = last.take()
         {
             lines.push(last_attempt);
             start = last_end;
-            attempt = line(engine, p, start..end, breakpoint, lines.last());
+            attempt = line(engine, p, start..end, breakpoint, lines.last(), lines.len());
         }
 
         // Finish the current line if there is a mandatory line break (i.e. due
         // to "\n") or if the line doesn't fit horizontally already since then
         // no shorter line will be possible.
-        if breakpoint == Breakpoint::Mandatory || !width.fits(attempt.width) {
+        if breakpoint == Breakpoint::Mandatory
+            || !available(lines.len()).fits(attempt.width)
+        {
             lines.push(attempt);
             start = end;
             last = None;
@@ -233,8 +243,15 @@ fn linebreak_optimized<'a>(
 
     // Determines the exact costs of a likely good layout through Knuth-Plass
     // with approximate metrics. We can use this cost as an upper bound to prune
-    // the search space in our proper optimization pass below.
-    let upper_bound = linebreak_optimized_approximate(engine, p, width, &metrics);
+    // the search space in our proper optimization pass below. When some lines
+    // are shortened by an exclusion, the optimal layout also depends on how
+    // many lines precede a breakpoint, which the approximation doesn't
+    // consider. Then, we forgo the bound.
+    let upper_bound = if p.config.exclusion.is_some() {
+        Cost::INFINITY
+    } else {
+        linebreak_optimized_approximate(engine, p, width, &metrics)
+    };
 
     // Using the upper bound, perform exact optimized linebreaking.
     linebreak_optimized_bounded(engine, p, width, &metrics, upper_bound)
@@ -256,10 +273,17 @@ fn linebreak_optimized_bounded<'a>(
         total: Cost,
         line: Line<'a>,
         end: usize,
+        index: usize,
     }
 
     // Dynamic programming table.
-    let mut table = vec![Entry { pred: 0, total: 0.0, line: Line::empty(), end: 0 }];
+    let mut table = vec![Entry {
+        pred: 0,
+        total: 0.0,
+        line: Line::empty(),
+        end: 0,
+        index: 0,
+    }];
 
     let mut active = 0;
     let mut prev_end = 0;
@@ -284,13 +308,19 @@ fn linebreak_optimized_bounded<'a>(
             }
 
             // Build the line.
-            let attempt = line(engine, p, start..end, breakpoint, Some(&pred.line));
+            let attempt =
+                line(engine, p, start..end, breakpoint, Some(&pred.line), pred.index);
+
+            // Determine the width available to the line, which can be reduced
+            // by an exclusion.
+            let (left, right) = p.config.excluded(pred.index);
+            let available = width - left - right;
 
             // Determine the cost of the line and its stretch ratio.
             let (line_ratio, line_cost) = ratio_and_cost(
                 p,
                 metrics,
-                width,
+                available,
                 &pred.line,
                 &attempt,
                 breakpoint,
@@ -335,7 +365,13 @@ fn linebreak_optimized_bounded<'a>(
 
             // If this attempt is better than what we had before, take it!
             if best.as_ref().is_none_or(|best| best.total >= total) {
-                best = Some(Entry { pred: pred_index, total, line: attempt, end });
+                best = Some(Entry {
+                    pred: pred_index,
+                    total,
+                    line: attempt,
+                    end,
+                    index: pred.index + 1,
+                });
             }
         }
 
@@ -504,10 +540,10 @@ fn linebreak_optimized_approximate(
     // got here is only likely to be good, not guaranteed to be the best. We now
     // computes its exact cost as that gives us a sound upper bound for the
     // proper optimization pass.
-    for idx in indices.into_iter().rev() {
+    for (index, idx) in indices.into_iter().rev().enumerate() {
         let Entry { end, breakpoint, unbreakable, .. } = table[idx];
 
-        let attempt = line(engine, p, start..end, breakpoint, Some(&pred));
+        let attempt = line(engine, p, start..end, breakpoint, Some(&pred), index);
         let (ratio, line_cost) =
             ratio_and_cost(p, metrics, width, &pred, &attempt, breakpoint, unbreakable);
 
@@ -788,12 +824,20 @@ fn hyphenations(
     word: &str,
     mut f: impl FnMut(usize, Breakpoint),
 ) {
-    let Some(lang) = lang_at(p, offset) else { return };
+    // Custom exceptions take precedence over the language's patterns.
+    let syllables = match exception_syllables(p, offset, word) {
+        Some(syllables) => Either::Left(syllables.into_iter()),
+        None => {
+            let Some(lang) = lang_at(p, offset) else { return };
+            Either::Right(hypher::hyphenate(word, lang))
+        }
+    };
+
     let count = word.chars().count();
     let end = offset + word.len();
 
     let mut chars = 0;
-    for syllable in hypher::hyphenate(word, lang) {
+    for syllable in syllables {
         offset += syllable.len();
         chars += syllable.chars().count();
 
@@ -825,6 +869,48 @@ fn hyphenations(
     }
 }
 
+/// Split a word into syllables according to a matching hyphenation exception
+/// at the given offset, if any.
+fn exception_syllables<'a>(
+    p: &Preparation,
+    offset: usize,
+    word: &'a str,
+) -> Option<Vec<&'a str>> {
+    let (_, item) = p.get(offset);
+    let exceptions = item.text()?.styles.get_ref(TextElem::hyphenation_exceptions);
+    let exception = exceptions.iter().find(|exception| {
+        let mut letters = exception.chars().filter(|&c| c != '-');
+        let mut chars = word.chars();
+        loop {
+            match (letters.next(), chars.next()) {
+                (Some(a), Some(b)) if a.to_lowercase().eq(b.to_lowercase()) => {}
+                (None, None) => break true,
+                _ => break false,
+            }
+        }
+    })?;
+
+    // Split the word at the positions of the exception's hyphens.
+    let mut syllables = vec![];
+    let mut start = 0;
+    let mut chars = word.char_indices().peekable();
+    for c in exception.chars() {
+        if c != '-' {
+            chars.next();
+            continue;
+        }
+
+        let end = chars.peek().map_or(word.len(), |&(i, _)| i);
+        if start < end && end < word.len() {
+            syllables.push(&word[start..end]);
+            start = end;
+        }
+    }
+
+    syllables.push(&word[start..]);
+    Some(syllables)
+}
+
 /// Produce linebreak opportunities for a link.
 fn linebreak_link(link: &str, mut f: impl FnMut(usize)) {
     #[derive(PartialEq)]
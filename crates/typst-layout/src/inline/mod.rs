@@ -6,9 +6,11 @@ mod finalize;
 mod line;
 mod linebreak;
 mod prepare;
+mod ruby;
 mod shaping;
 
 pub use self::box_::layout_box;
+pub use self::ruby::layout_ruby;
 pub use self::shaping::{SharedShapingContext, create_shape_plan, get_font_and_covers};
 
 use comemo::{Track, Tracked, TrackedMut};
@@ -19,7 +21,7 @@ use typst_library::introspection::{Introspector, Locator, LocatorLink, SplitLoca
 use typst_library::layout::{Abs, AlignElem, Dir, FixedAlignment, Fragment, Size};
 use typst_library::model::{
     EnumElem, FirstLineIndent, JustificationLimits, Linebreaks, ListElem, ParElem,
-    ParLine, ParLineMarker, TermsElem,
+    ParLine, ParLineMarker, TabStop, TermsElem,
 };
 use typst_library::routines::{Arenas, Pair, RealizationKind};
 use typst_library::text::{Costs, Lang, TextElem};
@@ -34,13 +36,14 @@ use self::linebreak::{Breakpoint, linebreak};
 use self::prepare::{Preparation, prepare};
 use self::shaping::{
     BEGIN_PUNCT_PAT, END_PUNCT_PAT, ShapedGlyph, ShapedText, cjk_punct_style,
-    is_of_cj_script, shape_range,
+    is_of_cj_script, is_synthesized_smallcap, shape_range,
 };
 
 /// Range of a substring of text.
 type Range = std::ops::Range<usize>;
 
 /// Layouts the paragraph.
+#[allow(clippy::too_many_arguments)]
 pub fn layout_par(
     elem: &Packed<ParElem>,
     engine: &mut Engine,
@@ -49,6 +52,7 @@ pub fn layout_par(
     region: Size,
     expand: bool,
     situation: ParSituation,
+    exclusion: Option<Exclusion>,
 ) -> SourceResult<Fragment> {
     layout_par_impl(
         elem,
@@ -63,6 +67,7 @@ pub fn layout_par(
         region,
         expand,
         situation,
+        exclusion,
     )
 }
 
@@ -82,6 +87,7 @@ fn layout_par_impl(
     region: Size,
     expand: bool,
     situation: ParSituation,
+    exclusion: Option<Exclusion>,
 ) -> SourceResult<Fragment> {
     let introspector = Protected::from_raw(introspector);
     let link = LocatorLink::new(locator);
@@ -118,6 +124,8 @@ fn layout_par_impl(
             linebreaks: elem.linebreaks.get(styles),
             first_line_indent: elem.first_line_indent.get(styles),
             hanging_indent: elem.hanging_indent.resolve(styles),
+            tab_stops: elem.tab_stops.get_cloned(styles),
+            exclusion,
         },
     )
 }
@@ -144,6 +152,8 @@ pub fn layout_inline<'a>(
             linebreaks: shared.get(ParElem::linebreaks),
             first_line_indent: shared.get(ParElem::first_line_indent),
             hanging_indent: shared.resolve(ParElem::hanging_indent),
+            tab_stops: shared.get_cloned(ParElem::tab_stops),
+            exclusion: None,
         },
     )
 }
@@ -218,6 +228,8 @@ fn configuration(
         } else {
             Abs::zero()
         },
+        tab_stops: base.tab_stops.clone(),
+        exclusion: base.exclusion,
         numbering_marker: shared.get_cloned(ParLine::numbering).map(|numbering| {
             Packed::new(ParLineMarker::new(
                 numbering,
@@ -263,6 +275,8 @@ struct ConfigBase {
     linebreaks: Smart<Linebreaks>,
     first_line_indent: FirstLineIndent,
     hanging_indent: Abs,
+    tab_stops: Vec<TabStop>,
+    exclusion: Option<Exclusion>,
 }
 
 /// Shared configuration for the whole inline layout.
@@ -277,6 +291,10 @@ struct Config {
     first_line_indent: Abs,
     /// The indent that all but the first line of a paragraph should have.
     hanging_indent: Abs,
+    /// The positions to which tabs advance.
+    tab_stops: Vec<TabStop>,
+    /// An area beside the first lines that the lines must avoid.
+    exclusion: Option<Exclusion>,
     /// Configuration for line numbering.
     numbering_marker: Option<Packed<ParLineMarker>>,
     /// The resolved horizontal alignment.
@@ -299,6 +317,33 @@ struct Config {
     costs: Costs,
 }
 
+impl Config {
+    /// How much horizontal space the exclusion takes away from the line with
+    /// the given index, on the left and on the right.
+    fn excluded(&self, index: usize) -> (Abs, Abs) {
+        match self.exclusion {
+            Some(exclusion) if index < exclusion.lines => match exclusion.side {
+                FixedAlignment::End => (Abs::zero(), exclusion.width),
+                _ => (exclusion.width, Abs::zero()),
+            },
+            _ => (Abs::zero(), Abs::zero()),
+        }
+    }
+}
+
+/// An area beside the first lines of a paragraph that the lines must avoid,
+/// for example because content is placed there with `place(wrap: true)`.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct Exclusion {
+    /// The side of the paragraph on which the area lies. `Start` is the left
+    /// and `End` the right side.
+    pub side: FixedAlignment,
+    /// How much horizontal space the area takes away from the lines.
+    pub width: Abs,
+    /// How many lines, counted from the first one, are beside the area.
+    pub lines: usize,
+}
+
 /// Get a style property, but only if it is the same for all of the children.
 fn shared_get<T: PartialEq>(
     children: &[Pair],
@@ -1,6 +1,7 @@
 use either::Either;
+use typst_library::diag::warning;
 use typst_library::layout::{Dir, Em};
-use typst_library::text::TextElem;
+use typst_library::text::{Smallcaps, TextElem};
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 
 use super::*;
@@ -92,7 +93,18 @@ pub fn prepare<'a>(
 
         match segment {
             Segment::Text(_, styles) => {
-                shape_range(&mut items, engine, text, &bidi, range, styles);
+                let start = items.len();
+                shape_range(&mut items, engine, text, &bidi, range.clone(), styles);
+                if let Some(sc) = styles.get(TextElem::smallcaps)
+                    && synthesizes_smallcaps(&items[start..], sc)
+                {
+                    engine.sink.warn(warning!(
+                        spans.span_at(range.start).0,
+                        "current font does not support small capitals";
+                        hint: "they were synthesized from scaled uppercase letters";
+                        hint: "use a font with small capitals for better results";
+                    ));
+                }
             }
             Segment::Item(item) => items.push((range, item)),
         }
@@ -173,3 +185,14 @@ fn add_cjk_latin_spacing(items: &mut [(Range, Item)]) {
         prev = item;
     }
 }
+
+/// Whether small capitals were synthesized for any of the shaped items because
+/// their font lacks them.
+fn synthesizes_smallcaps(items: &[(Range, Item)], sc: Smallcaps) -> bool {
+    items.iter().filter_map(|(_, item)| item.text()).any(|shaped| {
+        shaped
+            .glyphs
+            .iter()
+            .any(|g| is_synthesized_smallcap(g.c, sc) && !g.font.font().has_smallcaps())
+    })
+}
@@ -0,0 +1,51 @@
+use typst_library::diag::SourceResult;
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, Resolve, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{
+    Abs, Axes, Frame, InlineItem, OuterVAlignment, Point, Region, Size,
+};
+use typst_library::text::{RubyElem, TextElem, TextSize};
+
+/// Lay out ruby as part of inline layout.
+#[typst_macros::time(name = "ruby", span = elem.span())]
+pub fn layout_ruby(
+    elem: &Packed<RubyElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Size,
+) -> SourceResult<Vec<InlineItem>> {
+    let mut locator = locator.split();
+
+    // Neither the base nor the annotation may break across lines.
+    let pod = Region::new(Size::new(Abs::inf(), region.y), Axes::splat(false));
+    let base = crate::layout_frame(engine, &elem.body, locator.next(&()), styles, pod)?;
+
+    let size = elem.size.resolve(styles);
+    let annotation = elem.annotation.clone().set(TextElem::size, TextSize(size.into()));
+    let annotation =
+        crate::layout_frame(engine, &annotation, locator.next(&()), styles, pod)?;
+
+    // If allowed, the annotation may extend into the neighbouring text by up to
+    // half of its text size on each side.
+    let overhang = if elem.overhang.get(styles) { size } else { Abs::zero() };
+    let width = base.width().max(annotation.width() - overhang);
+
+    let gap = elem.gap.resolve(styles);
+    let extra = annotation.height() + gap;
+    let (base_y, annotation_y) = match elem.position.get(styles) {
+        OuterVAlignment::Top => (extra, Abs::zero()),
+        OuterVAlignment::Bottom => (Abs::zero(), base.height() + gap),
+    };
+
+    let mut frame = Frame::soft(Size::new(width, base.height() + extra));
+    frame.set_baseline(base_y + base.baseline());
+    frame.push_frame(Point::new((width - base.width()) / 2.0, base_y), base);
+    frame.push_frame(
+        Point::new((width - annotation.width()) / 2.0, annotation_y),
+        annotation,
+    );
+
+    Ok(vec![InlineItem::Frame(frame)])
+}
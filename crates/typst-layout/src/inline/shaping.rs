@@ -15,7 +15,7 @@ use typst_library::layout::{Abs, Dir, Em, Frame, FrameItem, Point, Rel, Size};
 use typst_library::model::{JustificationLimits, ParElem};
 use typst_library::text::{
     FontFamily, FontInstance, FontVariant, FontVariations, Glyph, Lang, Region,
-    ShiftSettings, TextEdgeBounds, TextElem, TextItem, families, features,
+    ShiftSettings, Smallcaps, TextEdgeBounds, TextElem, TextItem, families, features,
     is_default_ignorable, language, variant,
 };
 use typst_utils::SliceExt;
@@ -29,6 +29,11 @@ const SHY: char = '\u{ad}';
 const SHY_STR: &str = "\u{ad}";
 const HYPHEN: char = '-';
 const HYPHEN_STR: &str = "-";
+const TATWEEL: char = '\u{640}';
+
+/// The scale at which small capitals are synthesized relative to the text
+/// size, if the font doesn't provide them.
+const SMALLCAPS_SCALE: f64 = 0.75;
 
 /// The result of shaping text.
 ///
@@ -217,6 +222,14 @@ impl ShapedGlyph {
         is_cjk_center_aligned_punctuation(self.c, style)
     }
 
+    /// Whether the glyph is a kashida point, that is, whether the connection
+    /// to the next letter may be elongated to justify Arabic text.
+    ///
+    /// Kashida points are the only glyphs with left-side stretchability.
+    pub fn is_kashida(&self) -> bool {
+        self.script == Script::Arabic && self.stretchability().0 > Em::zero()
+    }
+
     /// Whether the glyph is a western letter or number.
     pub fn is_letter_or_number(&self) -> bool {
         matches!(self.c.script(), Script::Latin | Script::Greek | Script::Cyrillic)
@@ -356,83 +369,114 @@ impl<'a> ShapedText<'a> {
             }
 
             let pos = Point::new(offset, top + shift - y_offset.at(size));
-            let glyphs: Vec<Glyph> = group
-                .iter()
-                .map(|shaped: &ShapedGlyph| {
-                    // Whether the glyph is _not_ trimmed end-of-line
-                    // whitespace. Trimmed whitespace has its advance width and
-                    // offset zeroed out and is not taken into account for
-                    // justification.
-                    let kept = self.glyphs.kept.contains(&i);
-
-                    let (x_advance, x_offset) = if kept {
-                        let adjustability_left = if justification_ratio < 0.0 {
-                            shaped.shrinkability().0
-                        } else {
-                            shaped.stretchability().0
-                        };
-                        let adjustability_right = if justification_ratio < 0.0 {
-                            shaped.shrinkability().1
-                        } else {
-                            shaped.stretchability().1
-                        };
-
-                        let justification_left = adjustability_left * justification_ratio;
-                        let mut justification_right =
-                            adjustability_right * justification_ratio;
-                        if shaped.is_justifiable() {
-                            justification_right +=
-                                Em::from_abs(extra_justification, glyph_size)
-                        }
-
-                        frame.size_mut().x += justification_left.at(glyph_size)
-                            + justification_right.at(glyph_size);
-
-                        (
-                            shaped.x_advance + justification_left + justification_right,
-                            shaped.x_offset + justification_left,
-                        )
+            let mut glyphs = Vec::with_capacity(group.len());
+            for shaped in group {
+                // Whether the glyph is _not_ trimmed end-of-line
+                // whitespace. Trimmed whitespace has its advance width and
+                // offset zeroed out and is not taken into account for
+                // justification.
+                let kept = self.glyphs.kept.contains(&i);
+
+                let (x_advance, x_offset, kashida) = if kept {
+                    let adjustability_left = if justification_ratio < 0.0 {
+                        shaped.shrinkability().0
                     } else {
-                        (Em::zero(), Em::zero())
+                        shaped.stretchability().0
                     };
-                    i += 1;
-
-                    // We may not be able to reach the offset completely if
-                    // it exceeds u16, but better to have a roughly correct
-                    // span offset than nothing.
-                    let mut span = spans.span_at(shaped.range.start);
-                    span.1 = span.1.saturating_add(span_offset.saturating_as());
-
-                    // |<---- a Glyph ---->|
-                    //  -->|ShapedGlyph|<--
-                    // +---+-----------+---+
-                    // |   |  *********|   |
-                    // |   |  *        |   |
-                    // |   |  *    ****|   |
-                    // |   |  *       *|   |
-                    // |   |  *********|   |
-                    // +---+--+--------+---+
-                    //   A   B     C     D
-                    // Note A, B, D could be positive, zero, or negative.
-                    // A: justification_left
-                    // B: ShapedGlyph's x_offset
-                    //    (though a small part of the glyph may go inside B)
-                    // B+C: ShapedGlyph's x_advance
-                    // D: justification_right
-                    // A+B: Glyph's x_offset
-                    // A+B+C+D: Glyph's x_advance
-                    Glyph {
-                        id: shaped.glyph_id,
-                        x_advance,
-                        x_offset,
-                        y_advance: Em::zero(),
-                        y_offset: Em::zero(),
-                        range: (shaped.range.start - range.start).saturating_as()
-                            ..(shaped.range.end - range.start).saturating_as(),
-                        span,
+                    let adjustability_right = if justification_ratio < 0.0 {
+                        shaped.shrinkability().1
+                    } else {
+                        shaped.stretchability().1
+                    };
+
+                    let justification_left = adjustability_left * justification_ratio;
+                    let mut justification_right =
+                        adjustability_right * justification_ratio;
+                    if shaped.is_justifiable() {
+                        justification_right +=
+                            Em::from_abs(extra_justification, glyph_size)
                     }
-                })
-                .collect();
+
+                    frame.size_mut().x += justification_left.at(glyph_size)
+                        + justification_right.at(glyph_size);
+
+                    // At a kashida point, the space to the left is filled with
+                    // tatweels instead of being left empty.
+                    let kashida = if shaped.is_kashida() {
+                        justification_left.max(Em::zero())
+                    } else {
+                        Em::zero()
+                    };
+
+                    (
+                        shaped.x_advance + justification_left + justification_right
+                            - kashida,
+                        shaped.x_offset + justification_left - kashida,
+                        kashida,
+                    )
+                } else {
+                    (Em::zero(), Em::zero(), Em::zero())
+                };
+                i += 1;
+
+                // We may not be able to reach the offset completely if
+                // it exceeds u16, but better to have a roughly correct
+                // span offset than nothing.
+                let mut span = spans.span_at(shaped.range.start);
+                span.1 = span.1.saturating_add(span_offset.saturating_as());
+                let glyph_range = (shaped.range.start - range.start).saturating_as()
+                    ..(shaped.range.end - range.start).saturating_as();
+
+                // Elongate the connection to the next letter with tatweels.
+                // Their advances are squeezed such that they exactly fill the
+                // space, which makes consecutive tatweels overlap slightly.
+                if kashida > Em::zero()
+                    && let Some(id) = font.ttf().glyph_index(TATWEEL)
+                    && let Some(width) = font.x_advance(id.0)
+                    && width > Em::zero()
+                {
+                    let count = (kashida / width).ceil();
+                    for _ in 0..count as usize {
+                        glyphs.push(Glyph {
+                            id: id.0,
+                            x_advance: kashida / count,
+                            x_offset: Em::zero(),
+                            y_advance: Em::zero(),
+                            y_offset: Em::zero(),
+                            range: glyph_range.clone(),
+                            span,
+                        });
+                    }
+                }
+
+                // |<---- a Glyph ---->|
+                //  -->|ShapedGlyph|<--
+                // +---+-----------+---+
+                // |   |  *********|   |
+                // |   |  *        |   |
+                // |   |  *    ****|   |
+                // |   |  *       *|   |
+                // |   |  *********|   |
+                // +---+--+--------+---+
+                //   A   B     C     D
+                // Note A, B, D could be positive, zero, or negative.
+                // A: justification_left
+                // B: ShapedGlyph's x_offset
+                //    (though a small part of the glyph may go inside B)
+                // B+C: ShapedGlyph's x_advance
+                // D: justification_right
+                // A+B: Glyph's x_offset
+                // A+B+C+D: Glyph's x_advance
+                glyphs.push(Glyph {
+                    id: shaped.glyph_id,
+                    x_advance,
+                    x_offset,
+                    y_advance: Em::zero(),
+                    y_offset: Em::zero(),
+                    range: glyph_range,
+                    span,
+                });
+            }
 
             let item = TextItem {
                 font,
@@ -814,6 +858,7 @@ fn shape<'a>(
 
     track_and_space(&mut ctx);
     calculate_adjustability(&mut ctx, lang, region);
+    add_kashida_points(&mut ctx, base, text);
 
     #[cfg(debug_assertions)]
     assert_all_glyphs_in_range(&ctx.glyphs, text, base..(base + text.len()));
@@ -975,9 +1020,24 @@ fn shape_segment<'a>(
         return;
     };
 
+    // Synthesize small capitals if they are requested, but the font doesn't
+    // provide them.
+    let synthesized = ctx
+        .styles
+        .get(TextElem::smallcaps)
+        .filter(|_| !font.font().has_smallcaps())
+        .map(|sc| SynthesizedSmallcaps::new(text, sc));
+
+    // Maps a cluster of the shaped text back to the original text and
+    // determines whether it is a synthesized small capital.
+    let origin = |cluster: u32| match &synthesized {
+        Some(synthesized) => synthesized.origin(cluster as usize),
+        None => (cluster as usize, false),
+    };
+
     // Fill the buffer with our text.
     let mut buffer = UnicodeBuffer::new();
-    buffer.push_str(text);
+    buffer.push_str(synthesized.as_ref().map_or(text, |s| s.text.as_str()));
     buffer.set_language(language(ctx.styles));
     if let Some(script) = ctx.styles.get(TextElem::script).custom().and_then(|script| {
         rustybuzz::Script::from_iso15924_tag(Tag::from_bytes(script.as_bytes()))
@@ -1044,7 +1104,7 @@ fn shape_segment<'a>(
     let mut i = 0;
     while i < infos.len() {
         let info = &infos[i];
-        let cluster = info.cluster as usize;
+        let (cluster, small) = origin(info.cluster);
 
         // Add the glyph to the shaped output.
         if info.glyph_id != 0 && is_covered(cluster) {
@@ -1078,8 +1138,9 @@ fn shape_segment<'a>(
                 };
 
                 // If the cluster doesn't match anymore, we've reached the end.
-                if next_info.cluster != info.cluster {
-                    break base + next_info.cluster as usize;
+                let (next_cluster, _) = origin(next_info.cluster);
+                if next_cluster != cluster {
+                    break base + next_cluster;
                 }
 
                 k = next;
@@ -1095,7 +1156,11 @@ fn shape_segment<'a>(
                 x_advance,
                 x_offset: font.to_em(pos[i].x_offset) + script_compensation,
                 y_offset: font.to_em(pos[i].y_offset) + script_shift,
-                size: scale.at(ctx.size),
+                size: if small {
+                    (scale * SMALLCAPS_SCALE).at(ctx.size)
+                } else {
+                    scale.at(ctx.size)
+                },
                 adjustability: Adjustability::default(),
                 range: start..end,
                 safe_to_break: !info.unsafe_to_break(),
@@ -1112,7 +1177,7 @@ fn shape_segment<'a>(
             // First, search for the end of the tofu sequence.
             let k = i;
             while infos.get(i + 1).is_some_and(|info| {
-                info.glyph_id == 0 || !is_covered(info.cluster as usize)
+                info.glyph_id == 0 || !is_covered(origin(info.cluster).0)
             }) {
                 i += 1;
             }
@@ -1136,10 +1201,10 @@ fn shape_segment<'a>(
             // Glyphs:   E   C   _   _   A
             // Clusters: 8   6   4   2   0
             //                  k=2 i=3
-            let start = infos[if ltr { k } else { i }].cluster as usize;
+            let (start, _) = origin(infos[if ltr { k } else { i }].cluster);
             let end = if ltr { i.checked_add(1) } else { k.checked_sub(1) }
                 .and_then(|last| infos.get(last))
-                .map_or(text.len(), |info| info.cluster as usize);
+                .map_or(text.len(), |info| origin(info.cluster).0);
 
             // Trim half-baked cluster.
             let remove = base + start..base + end;
@@ -1215,6 +1280,52 @@ fn determine_shift(
         })
 }
 
+/// Text in which the letters that should become small capitals are
+/// uppercased, so that they can be shaped at a smaller size.
+struct SynthesizedSmallcaps {
+    /// The text with uppercased letters.
+    text: String,
+    /// For each char in `text`: Its byte offset, the byte offset of the char in
+    /// the original text it stems from, and whether it is a small capital.
+    chars: Vec<(usize, usize, bool)>,
+}
+
+impl SynthesizedSmallcaps {
+    /// Uppercase the letters that should become small capitals.
+    fn new(original: &str, sc: Smallcaps) -> Self {
+        let mut text = String::with_capacity(original.len());
+        let mut chars = Vec::with_capacity(original.len());
+        for (i, c) in original.char_indices() {
+            if is_synthesized_smallcap(c, sc) {
+                // Uppercasing may yield multiple chars, as for `ß`. They all
+                // map back to the same original char.
+                for upper in c.to_uppercase() {
+                    chars.push((text.len(), i, true));
+                    text.push(upper);
+                }
+            } else {
+                chars.push((text.len(), i, false));
+                text.push(c);
+            }
+        }
+        Self { text, chars }
+    }
+
+    /// Map a byte offset in the uppercased text back to the original text and
+    /// determine whether it belongs to a small capital.
+    fn origin(&self, offset: usize) -> (usize, bool) {
+        let i = self.chars.partition_point(|&(o, _, _)| o <= offset);
+        let (_, origin, small) = self.chars[i.saturating_sub(1)];
+        (origin, small)
+    }
+}
+
+/// Whether the given char becomes a small capital when small capitals are
+/// synthesized.
+pub fn is_synthesized_smallcap(c: char, sc: Smallcaps) -> bool {
+    c.is_lowercase() || (sc == Smallcaps::All && c.is_uppercase())
+}
+
 /// Create a shape plan.
 #[comemo::memoize]
 pub fn create_shape_plan(
@@ -1337,6 +1448,81 @@ fn calculate_adjustability(ctx: &mut ShapingContext, lang: Lang, region: Option<
     }
 }
 
+/// The maximum elongation at a kashida point, in multiples of the tatweel's
+/// width.
+const KASHIDA_LIMIT: f64 = 3.0;
+
+/// Mark one kashida point per Arabic word, if enabled.
+///
+/// The kashida is placed at the last connection within the word, where it is
+/// least disruptive. It is realized as left-side stretchability, since the
+/// following letter is to the left in right-to-left text.
+fn add_kashida_points(ctx: &mut ShapingContext, base: usize, text: &str) {
+    if !ctx.styles.get(TextElem::kashida) {
+        return;
+    }
+
+    let mut best: Option<usize> = None;
+    for i in 0..=ctx.glyphs.len() {
+        let Some(glyph) = ctx.glyphs.get(i).filter(|glyph| !glyph.is_space()) else {
+            // At the end of a word, apply the kashida to the best candidate.
+            if let Some(k) = best.take() {
+                let glyph = &mut ctx.glyphs[k];
+                if let Some(id) = glyph.font.ttf().glyph_index(TATWEEL)
+                    && let Some(width) = glyph.font.x_advance(id.0)
+                {
+                    glyph.adjustability.stretchability.0 = width * KASHIDA_LIMIT;
+                }
+            }
+            continue;
+        };
+
+        // Only consider glyphs that are alone in their cluster.
+        let alone = [i.checked_sub(1), i.checked_add(1)].into_iter().all(|j| {
+            j.and_then(|j| ctx.glyphs.get(j))
+                .is_none_or(|other| other.range.start != glyph.range.start)
+        });
+
+        let next = text[glyph.range.end - base..].chars().next();
+        if alone
+            && joins_next(glyph.c, next)
+            && best.is_none_or(|k| ctx.glyphs[k].range.start < glyph.range.start)
+        {
+            best = Some(i);
+        }
+    }
+}
+
+/// Whether an Arabic letter connects to the following letter.
+fn joins_next(c: char, next: Option<char>) -> bool {
+    // Letters that only join to the preceding letter.
+    let right_joining = |c| {
+        matches!(
+            c,
+            '\u{621}'..='\u{625}'
+                | '\u{627}'
+                | '\u{629}'
+                | '\u{62F}'..='\u{632}'
+                | '\u{648}'
+                | '\u{671}'..='\u{673}'
+                | '\u{675}'..='\u{677}'
+                | '\u{688}'..='\u{699}'
+                | '\u{6C0}'
+                | '\u{6C3}'..='\u{6CB}'
+                | '\u{6CD}'
+                | '\u{6CF}'
+                | '\u{6D2}'
+                | '\u{6D3}'
+                | '\u{6D5}'
+        )
+    };
+
+    let is_letter = |c: char| c.script() == Script::Arabic && c.is_alphabetic();
+    is_letter(c)
+        && !right_joining(c)
+        && next.is_some_and(|next| is_letter(next) && next != '\u{621}')
+}
+
 /// Difference between non-breaking and normal space.
 fn nbsp_delta(font: &FontInstance) -> Option<Em> {
     let space = font.ttf().glyph_index(' ')?.0;
@@ -9,6 +9,7 @@ mod introspect;
 mod lists;
 mod math;
 mod modifiers;
+mod overlay;
 mod pad;
 mod pages;
 mod repeat;
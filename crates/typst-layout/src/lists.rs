@@ -295,6 +295,7 @@ fn layout_items(
         layouter.span,
         Some(layouter.gutter.into()),
         Dir::TTB,
+        false,
         engine,
         // This locator should not be used by cells.
         locator.next(&()),
@@ -0,0 +1,53 @@
+use typst_library::diag::SourceResult;
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, Resolve, StyleChain, StyledElem};
+use typst_library::introspection::Locator;
+use typst_library::layout::{
+    AlignElem, Axes, FixedAlignment, Frame, OverlayElem, Region, Size,
+};
+
+/// Layout the overlay.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_overlay(
+    elem: &Packed<OverlayElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let mut locator = locator.split();
+    let pod = Region::new(region.size, Axes::splat(false));
+
+    // Layout all children, keeping track of the largest extent.
+    let mut size = Size::zero();
+    let mut layers = Vec::with_capacity(elem.children.len());
+    for child in &elem.children {
+        // Like in stacks, the alignment of an `AlignElem` child is respected.
+        let align = if let Some(align) = child.to_packed::<AlignElem>() {
+            align.alignment.get(styles)
+        } else if let Some(styled) = child.to_packed::<StyledElem>() {
+            styles.chain(&styled.styles).get(AlignElem::alignment)
+        } else {
+            styles.get(AlignElem::alignment)
+        }
+        .resolve(styles);
+
+        let frame =
+            crate::layout_frame(engine, child, locator.next(&child.span()), styles, pod)?;
+        size = size.max(frame.size());
+        layers.push((frame, align));
+    }
+
+    let size = region.expand.select(region.size, size);
+    let mut output = Frame::soft(size);
+
+    // Align each child within the overlay's area.
+    for (frame, align) in layers {
+        let pos = align
+            .zip_map(size - frame.size(), FixedAlignment::position)
+            .to_point();
+        output.push_frame(pos, frame);
+    }
+
+    Ok(output)
+}
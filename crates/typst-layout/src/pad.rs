@@ -77,12 +77,16 @@ pub fn shrink_multiple(
 ///   <=> w - p.rel * w - p.abs = s
 ///   <=> (1 - p.rel) * w = s + p.abs
 ///   <=> w = (s + p.abs) / (1 - p.rel)
+///
+/// With negative insets, the grown size may become negative if the frame is
+/// smaller than the insets. It is clamped to zero in that case.
 pub fn grow(frame: &mut Frame, inset: &Sides<Rel<Abs>>) {
     // Apply the padding inversely such that the grown size padded
     // yields the frame's size.
     let padded = frame
         .size()
-        .zip_map(inset.sum_by_axis(), |s, p| (s + p.abs) / (1.0 - p.rel.get()));
+        .zip_map(inset.sum_by_axis(), |s, p| (s + p.abs) / (1.0 - p.rel.get()))
+        .max(Size::zero());
 
     let inset = inset.relative_to(padded);
     let offset = Point::new(inset.left, inset.top);
@@ -1,7 +1,9 @@
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
 use typst_library::introspection::{ManualPageCounter, Tag};
-use typst_library::layout::{Frame, FrameItem, Point};
+use typst_library::layout::{Abs, Frame, FrameItem, Point, Sides, Size};
+use typst_library::visualize::{Cmyk, Color, Curve, FixedStroke, Geometry, ProcessColor};
+use typst_syntax::Span;
 
 use super::LayoutedPage;
 use crate::Page;
@@ -19,11 +21,13 @@ pub fn finalize(
         margin_two_sided,
         mut bleed,
         bleed_two_sided,
+        crop_marks,
         binding,
         header,
         footer,
         background,
         foreground,
+        placed,
         fill,
         numbering,
         supplement,
@@ -63,6 +67,12 @@ pub fn finalize(
     // Add the inner contents.
     frame.push_frame(Point::new(margin.left, margin.top), inner);
 
+    // Add page-scoped placed content, which is positioned relative to the
+    // full page.
+    if let Some(placed) = placed {
+        frame.push_frame(Point::zero(), placed);
+    }
+
     // Add the "after" marginals.
     if let Some(footer) = footer {
         let y = frame.height() - footer.height();
@@ -72,6 +82,10 @@ pub fn finalize(
         frame.push_frame(bleed_origin, foreground);
     }
 
+    // Add printer's marks outside of the bleed.
+    let slug =
+        if crop_marks { add_printer_marks(&mut frame, bleed) } else { Abs::zero() };
+
     // Apply counter updates from within the page to the manual page counter.
     counter.visit(engine, &frame)?;
 
@@ -79,5 +93,119 @@ pub fn finalize(
     let number = counter.logical();
     counter.step();
 
-    Ok(Page { frame, bleed, fill, numbering, supplement, number })
+    Ok(Page {
+        frame,
+        bleed,
+        slug,
+        fill,
+        numbering,
+        supplement,
+        number,
+    })
+}
+
+/// Adds crop marks at the corners and registration marks at the middle of the
+/// edges of the page, outside of the bleed. Returns the amount of space needed
+/// around the bleed to fit the marks.
+fn add_printer_marks(frame: &mut Frame, bleed: Sides<Abs>) -> Abs {
+    // The distance between the edge of the bleed and the marks.
+    let offset = Abs::pt(6.0);
+    // The length of crop marks and the diameter of registration marks.
+    let length = Abs::pt(18.0);
+
+    // The registration color, which prints on all separations.
+    let registration =
+        Color::Process(ProcessColor::Cmyk(Cmyk { c: 1.0, m: 1.0, y: 1.0, k: 1.0 }));
+    let stroke = FixedStroke::from_pair(registration, Abs::pt(0.25));
+    let mut push = |pos: Point, geometry: Geometry| {
+        let shape = geometry.stroked(stroke.clone());
+        frame.push(pos, FrameItem::Shape(shape, Span::detached()));
+    };
+
+    let size = frame.size();
+
+    // Crop marks continue the edges of the trimmed page outwards, starting
+    // beyond the bleed.
+    for (x, dx, bleed_x) in [(Abs::zero(), -1.0, bleed.left), (size.x, 1.0, bleed.right)]
+    {
+        for (y, dy, bleed_y) in
+            [(Abs::zero(), -1.0, bleed.top), (size.y, 1.0, bleed.bottom)]
+        {
+            let start_x = x + (bleed_x + offset) * dx;
+            let start_y = y + (bleed_y + offset) * dy;
+            push(Point::new(start_x, y), Geometry::Line(Point::with_x(length * dx)));
+            push(Point::new(x, start_y), Geometry::Line(Point::with_y(length * dy)));
+        }
+    }
+
+    // Registration marks are centered in the mark area of each edge.
+    let half = length / 2.0;
+    let reach = offset + half;
+    let centers = [
+        Point::new(size.x / 2.0, -bleed.top - reach),
+        Point::new(size.x / 2.0, size.y + bleed.bottom + reach),
+        Point::new(-bleed.left - reach, size.y / 2.0),
+        Point::new(size.x + bleed.right + reach, size.y / 2.0),
+    ];
+
+    let circle = Size::splat(half);
+    for center in centers {
+        push(center - Point::with_x(half), Geometry::Line(Point::with_x(length)));
+        push(center - Point::with_y(half), Geometry::Line(Point::with_y(length)));
+        push(center - circle.to_point() / 2.0, Geometry::Curve(Curve::ellipse(circle)));
+    }
+
+    offset + length + offset
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_library::layout::Rect;
+
+    use super::*;
+
+    #[test]
+    fn test_printer_marks_surround_bleed() {
+        let size = Size::new(Abs::pt(100.0), Abs::pt(50.0));
+        let bleed = Sides::new(Abs::pt(1.0), Abs::pt(2.0), Abs::pt(3.0), Abs::pt(4.0));
+        let mut frame = Frame::hard(size);
+        let slug = add_printer_marks(&mut frame, bleed);
+        assert_eq!(slug, Abs::pt(30.0));
+
+        // Two lines for each corner and a cross and circle for each edge.
+        assert_eq!(frame.items().len(), 4 * 2 + 4 * 3);
+
+        // The extent of the bleed and of the space around it.
+        let bleed_area = Rect::new(
+            Point::new(-bleed.left, -bleed.top),
+            Point::new(size.x + bleed.right, size.y + bleed.bottom),
+        );
+        let slug_area = Rect::new(
+            bleed_area.min - Point::splat(slug),
+            bleed_area.max + Point::splat(slug),
+        );
+
+        for (pos, item) in frame.items() {
+            let FrameItem::Shape(shape, _) = item else {
+                panic!("expected only shapes, found {item:?}");
+            };
+
+            let bbox = shape.bbox(false);
+            let (min, max) = (*pos + bbox.min, *pos + bbox.max);
+            assert!(
+                max.x < bleed_area.min.x
+                    || min.x > bleed_area.max.x
+                    || max.y < bleed_area.min.y
+                    || min.y > bleed_area.max.y,
+                "mark at {min:?}..{max:?} overlaps with the bleed",
+            );
+            assert!(
+                min.x >= slug_area.min.x
+                    && max.x <= slug_area.max.x
+                    && min.y >= slug_area.min.y
+                    && max.y <= slug_area.max.y,
+                "mark at {min:?}..{max:?} exceeds the space for marks",
+            );
+        }
+    }
 }
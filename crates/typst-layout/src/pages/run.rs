@@ -1,16 +1,17 @@
 use comemo::{Track, Tracked, TrackedMut};
-use typst_library::diag::SourceResult;
+use typst_library::diag::{SourceResult, warning};
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{
     Content, NativeElement, Resolve, Smart, StyleChain, Styles,
 };
 use typst_library::introspection::{
     Counter, CounterDisplayElem, CounterKey, Introspector, Locator, LocatorLink,
+    SplitLocator,
 };
 use typst_library::layout::{
-    Abs, AlignElem, Alignment, Axes, Binding, ColumnsElem, Dir, Frame, HAlignment,
-    Length, OuterVAlignment, PageElem, Paper, Region, Regions, Rel, Sides, Size,
-    VAlignment,
+    Abs, AlignElem, Alignment, Axes, Binding, ColumnsElem, Dir, FixedAlignment, Frame,
+    HAlignment, Length, OuterVAlignment, PageElem, Paper, PlaceElem, PlacementScope,
+    Point, Region, Regions, Rel, Sides, Size, VAlignment,
 };
 use typst_library::model::Numbering;
 use typst_library::pdf::ArtifactKind;
@@ -20,7 +21,7 @@ use typst_library::visualize::Paint;
 use typst_library::{Library, World};
 use typst_utils::{LazyHash, Numeric, Protected};
 
-use crate::flow::{FlowMode, layout_flow};
+use crate::flow::{FlowMode, find_in_frame, layout_flow};
 
 /// A mostly finished layout for one page. Needs only knowledge of its exact
 /// page number to be finalized into a `Page`. (Because the margins can depend
@@ -32,11 +33,13 @@ pub struct LayoutedPage {
     pub margin_two_sided: bool,
     pub bleed: Sides<Abs>,
     pub bleed_two_sided: bool,
+    pub crop_marks: bool,
     pub binding: Binding,
     pub header: Option<Frame>,
     pub footer: Option<Frame>,
     pub background: Option<Frame>,
     pub foreground: Option<Frame>,
+    pub placed: Option<Frame>,
     pub fill: Smart<Option<Paint>>,
     pub numbering: Option<Numbering>,
     pub supplement: Content,
@@ -135,6 +138,8 @@ fn layout_page_run_impl(
         .resolve(styles)
         .relative_to(size);
 
+    let crop_marks = styles.get(PageElem::crop_marks);
+
     let fill = styles.get_cloned(PageElem::fill);
     let foreground = styles.get_ref(PageElem::foreground);
     let background = styles.get_ref(PageElem::background);
@@ -198,18 +203,28 @@ fn layout_page_run_impl(
         FlowMode::Root,
     )?;
 
+    // Layout page-scoped placed elements.
+    let placed = fragment
+        .iter()
+        .map(|inner| {
+            let page_size = inner.size() + margin.sum_by_axis();
+            layout_page_placed(&mut engine, &mut locator, styles, inner, page_size)
+        })
+        .collect::<SourceResult<Vec<_>>>()?;
+
     // Layouts a single marginal.
     let mut layout_marginal = |content: &Option<Content>, area, align| {
         let Some(content) = content else { return Ok(None) };
         let aligned = content.clone().set(AlignElem::alignment, align);
-        crate::layout_frame(
+        let frame = crate::layout_frame(
             &mut engine,
             &aligned,
             locator.next(&content.span()),
             styles,
             Region::new(area, Axes::splat(true)),
-        )
-        .map(Some)
+        )?;
+        warn_unplaced(&mut engine, styles, &frame);
+        Ok(Some(frame))
     };
 
     // Layout marginals.
@@ -219,7 +234,7 @@ fn layout_page_run_impl(
     let footer = footer.clone().map(|f| f.artifact(ArtifactKind::Footer));
     let background = background.clone().map(|b| b.artifact(ArtifactKind::Background));
 
-    for inner in fragment {
+    for (inner, placed) in fragment.into_iter().zip(placed) {
         let header_size = Size::new(inner.width(), margin.top - header_ascent);
         let footer_size = Size::new(inner.width(), margin.bottom - footer_descent);
         let full_size = inner.size() + margin.sum_by_axis() + bleed.sum_by_axis();
@@ -233,13 +248,83 @@ fn layout_page_run_impl(
             footer: layout_marginal(&footer, footer_size, Alignment::TOP)?,
             background: layout_marginal(&background, full_size, mid)?,
             foreground: layout_marginal(foreground, full_size, mid)?,
+            placed,
             margin,
             margin_two_sided,
             bleed,
             bleed_two_sided,
+            crop_marks,
             binding,
         });
     }
 
     Ok(layouted)
 }
+
+/// Lays out the page-scoped placed elements whose tags ended up in a page's
+/// inner frame into a frame spanning the full page.
+fn layout_page_placed(
+    engine: &mut Engine,
+    locator: &mut SplitLocator,
+    styles: StyleChain,
+    inner: &Frame,
+    size: Size,
+) -> SourceResult<Option<Frame>> {
+    let mut elems = find_in_frame::<PlaceElem>(inner)
+        .into_iter()
+        .map(|(_, elem)| elem)
+        .filter(|elem| elem.scope.get(styles) == PlacementScope::Page)
+        .peekable();
+    if elems.peek().is_none() {
+        return Ok(None);
+    }
+
+    let mut output = Frame::soft(size);
+    for elem in elems {
+        // Lay out and position the content with the styles where it was
+        // placed rather than those of the page.
+        let styles = match &elem.styles {
+            Some(local) => styles.chain(local),
+            None => styles,
+        };
+
+        let frame = crate::layout_frame(
+            engine,
+            &elem.body,
+            locator.next(&elem.location()),
+            styles,
+            Region::new(size, Axes::splat(false)),
+        )?;
+        warn_unplaced(engine, styles, &frame);
+
+        // The alignment can't be `auto` because that is checked for during
+        // collection. Without a vertical alignment, we place at the top.
+        let alignment = elem.alignment.get(styles).unwrap_or(Alignment::START);
+        let align_x = alignment.x().unwrap_or_default().resolve(styles);
+        let align_y = alignment.y().map_or(FixedAlignment::Start, |y| y.resolve(styles));
+        let delta = Axes::new(elem.dx.get(styles), elem.dy.get(styles))
+            .resolve(styles)
+            .zip_map(size, Rel::relative_to)
+            .to_point();
+
+        let x = align_x.position(size.x - frame.width());
+        let y = align_y.position(size.y - frame.height());
+        output.push_frame(Point::new(x, y) + delta, frame);
+    }
+
+    Ok(Some(output))
+}
+
+/// Warns about page-scoped placed elements in a frame that is not searched for
+/// them, such as a page's header or footer. Their content is not laid out.
+fn warn_unplaced(engine: &mut Engine, styles: StyleChain, frame: &Frame) {
+    for (_, elem) in find_in_frame::<PlaceElem>(frame) {
+        if elem.scope.get(styles) == PlacementScope::Page {
+            engine.sink.warn(warning!(
+                elem.span(),
+                "page-scoped placement was ignored";
+                hint: "page-scoped placement is only supported in the body of a page";
+            ));
+        }
+    }
+}
@@ -2,36 +2,39 @@ use comemo::Track;
 use ecow::{EcoVec, eco_format};
 use smallvec::smallvec;
 use typst_library::diag::{At, SourceResult, bail};
+use typst_library::engine::Engine;
 use typst_library::foundations::{
     Content, Context, NativeElement, NativeRuleMap, Packed, Resolve, ShowFn, Smart,
     StyleChain, Synthesize, Target, dict,
 };
 use typst_library::introspection::{Counter, Locator, LocatorLink};
 use typst_library::layout::{
-    Abs, AlignElem, Alignment, Axes, BlockBody, BlockElem, ColumnsElem, Em,
-    FixedAlignment, GridCell, GridChild, GridElem, GridItem, HAlignment, HElem, HideElem,
-    InlineElem, LayoutElem, Length, MoveElem, OuterVAlignment, PadElem, PageElem,
-    PlaceElem, PlacementScope, Region, Rel, RepeatElem, RotateElem, ScaleElem, Sides,
-    Size, Sizing, SkewElem, Spacing, StackChild, StackElem, TrackSizings, VElem,
+    Abs, AlignElem, Alignment, Axes, BlockBody, BlockElem, ColumnsElem, Dir, Em,
+    FixedAlignment, Fr, GridCell, GridChild, GridElem, GridItem, HAlignment, HElem,
+    HideElem, InlineElem, LayoutElem, Length, MoveElem, OuterVAlignment, OverlayElem,
+    PadElem, PageElem, PlaceElem, PlacementScope, Region, Rel, RepeatElem, RotateElem,
+    ScaleElem, Sides, Size, Sizing, SkewElem, Spacing, StackChild, StackElem,
+    TrackSizings, TransformElem, VElem,
 };
 use typst_library::math::EquationElem;
 use typst_library::model::{
     Attribution, BibliographyElem, CiteElem, CiteGroup, CslIndentElem, CslLightElem,
-    Destination, DirectLinkElem, DividerElem, EmphElem, EnumElem, FigureCaption,
-    FigureElem, FootnoteElem, FootnoteEntry, HeadingElem, LinkElem, LinkMarker, ListElem,
-    OutlineElem, OutlineEntry, ParElem, ParbreakElem, QuoteElem, RefElem, StrongElem,
-    TableCell, TableElem, TermsElem, TitleElem, Works,
+    Destination, DirectLinkElem, DividerElem, EmphElem, EndnoteElem, EndnoteEntry,
+    EndnotesElem, EnumElem, FigureCaption, FigureElem, FootnoteElem, FootnoteEntry,
+    HeadingElem, LinkElem, LinkMarker, ListElem, MarginNoteElem, Numbering, OutlineElem,
+    OutlineEntry, ParElem, ParbreakElem, QuoteElem, RefElem, StrongElem, TableCell,
+    TableElem, TermsElem, TitleElem, Works,
 };
 use typst_library::pdf::{ArtifactElem, ArtifactKind, AttachElem, PdfMarkerTag};
 use typst_library::text::{
-    DecoLine, Decoration, HighlightElem, ItalicToggle, LinebreakElem, LocalName,
-    OverlineElem, RawElem, RawLine, ScriptKind, ShiftSettings, Smallcaps, SmallcapsElem,
-    SmartQuoteElem, SmartQuotes, SpaceElem, StrikeElem, SubElem, SuperElem, TextElem,
-    TextSize, UnderlineElem, WeightDelta,
+    DecoLine, Decoration, HighlightElem, IsolateElem, ItalicToggle, LinebreakElem,
+    LocalName, OverlineElem, RawElem, RawLine, RubyElem, ScriptKind, ShiftSettings,
+    Smallcaps, SmallcapsElem, SmartQuoteElem, SmartQuotes, SpaceElem, StrikeElem,
+    SubElem, SuperElem, TextElem, TextSize, UnderlineElem, WeightDelta,
 };
 use typst_library::visualize::{
-    CircleElem, CurveElem, EllipseElem, ImageElem, LineElem, PolygonElem, RectElem,
-    SquareElem, Stroke,
+    ArcElem, BlurElem, CircleElem, CurveElem, EllipseElem, ImageElem, LineElem,
+    OpacityElem, PolygonElem, RectElem, SquareElem, Stroke,
 };
 use typst_utils::{Get, Numeric};
 
@@ -56,6 +59,10 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Paged, QUOTE_RULE);
     rules.register(Paged, FOOTNOTE_RULE);
     rules.register(Paged, FOOTNOTE_ENTRY_RULE);
+    rules.register(Paged, ENDNOTE_RULE);
+    rules.register(Paged, ENDNOTES_RULE);
+    rules.register(Paged, ENDNOTE_ENTRY_RULE);
+    rules.register(Paged, MARGIN_NOTE_RULE);
     rules.register(Paged, OUTLINE_RULE);
     rules.register(Paged, OUTLINE_ENTRY_RULE);
     rules.register(Paged, REF_RULE);
@@ -74,6 +81,8 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Paged, STRIKE_RULE);
     rules.register(Paged, HIGHLIGHT_RULE);
     rules.register(Paged, SMALLCAPS_RULE);
+    rules.register(Paged, RUBY_RULE);
+    rules.register(Paged, ISOLATE_RULE);
     rules.register(Paged, RAW_RULE);
     rules.register(Paged, RAW_LINE_RULE);
 
@@ -82,12 +91,14 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Paged, PAD_RULE);
     rules.register(Paged, COLUMNS_RULE);
     rules.register(Paged, STACK_RULE);
+    rules.register(Paged, OVERLAY_RULE);
     rules.register(Paged, GRID_RULE);
     rules.register(Paged, GRID_CELL_RULE);
     rules.register(Paged, MOVE_RULE);
     rules.register(Paged, SCALE_RULE);
     rules.register(Paged, ROTATE_RULE);
     rules.register(Paged, SKEW_RULE);
+    rules.register(Paged, TRANSFORM_RULE);
     rules.register(Paged, REPEAT_RULE);
     rules.register(Paged, HIDE_RULE);
     rules.register(Paged, LAYOUT_RULE);
@@ -100,7 +111,10 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Paged, ELLIPSE_RULE);
     rules.register(Paged, CIRCLE_RULE);
     rules.register(Paged, POLYGON_RULE);
+    rules.register(Paged, ARC_RULE);
     rules.register(Paged, CURVE_RULE);
+    rules.register(Paged, OPACITY_RULE);
+    rules.register(Paged, BLUR_RULE);
 
     // Math.
     rules.register(Paged, EQUATION_RULE);
@@ -326,6 +340,10 @@ const FIGURE_RULE: ShowFn<FigureElem> = |elem, _, styles| {
 
     // Wrap in a float.
     if let Some(align) = elem.placement.get(styles) {
+        if elem.scope.get(styles) == PlacementScope::Page {
+            bail!(span, "page-scoped placement is not available for floating figures");
+        }
+
         realized = PlaceElem::new(realized)
             .with_alignment(align.map(|align| HAlignment::Center + align))
             .with_scope(elem.scope.get(styles))
@@ -401,6 +419,39 @@ const FOOTNOTE_ENTRY_RULE: ShowFn<FootnoteEntry> = |elem, engine, styles| {
     ]))
 };
 
+const ENDNOTE_RULE: ShowFn<EndnoteElem> = |elem, engine, styles| {
+    // The endnote number that links to the endnote entry.
+    let link = elem.realize(engine, styles)?;
+    let sup = SuperElem::new(link).pack().spanned(elem.span());
+    Ok(HElem::hole().clone() + sup)
+};
+
+const ENDNOTES_RULE: ShowFn<EndnotesElem> = |elem, engine, _| {
+    let entries = elem.notes(engine).into_iter().map(|note| {
+        let loc = note.location().unwrap();
+        let span = note.span();
+        // We attach a well-known derived location to the entry so that the
+        // note can link to it without first querying for it.
+        let entry = EndnoteEntry::new(note).pack().spanned(span).located(loc.variant(1));
+        BlockElem::packed(entry).spanned(span)
+    });
+    Ok(Content::sequence(entries))
+};
+
+const ENDNOTE_ENTRY_RULE: ShowFn<EndnoteEntry> = |elem, engine, styles| {
+    let number_gap = Em::new(0.05);
+    let (sup, body) = elem.realize(engine, styles)?;
+    Ok(Content::sequence([
+        sup,
+        HElem::new(number_gap.into()).with_weak(true).pack(),
+        body,
+    ]))
+};
+
+// The note itself is laid out by the root flow, which finds it through its
+// introspection tag.
+const MARGIN_NOTE_RULE: ShowFn<MarginNoteElem> = |_, _, _| Ok(Content::empty());
+
 const OUTLINE_RULE: ShowFn<OutlineElem> = |elem, engine, styles| {
     let title = elem.realize_title(styles);
     let entries = elem.realize_flat(engine, styles)?;
@@ -643,9 +694,34 @@ const SMALLCAPS_RULE: ShowFn<SmallcapsElem> = |elem, _, styles| {
     Ok(elem.body.clone().set(TextElem::smallcaps, Some(sc)))
 };
 
-const RAW_RULE: ShowFn<RawElem> = |elem, _, styles| {
+const RUBY_RULE: ShowFn<RubyElem> = |elem, _, _| {
+    Ok(InlineElem::layouter(elem.clone(), crate::inline::layout_ruby).pack())
+};
+
+const ISOLATE_RULE: ShowFn<IsolateElem> = |elem, _, styles| {
+    // Surround the content with the Unicode isolate control characters, which
+    // are respected by the bidirectional reordering in inline layout.
+    let start = match elem.dir.get(styles).0 {
+        Smart::Auto => "\u{2068}",
+        Smart::Custom(Dir::LTR) => "\u{2066}",
+        Smart::Custom(_) => "\u{2067}",
+    };
+    Ok(Content::sequence([
+        TextElem::packed(start).spanned(elem.span()),
+        elem.body.clone(),
+        TextElem::packed("\u{2069}").spanned(elem.span()),
+    ]))
+};
+
+const RAW_RULE: ShowFn<RawElem> = |elem, engine, styles| {
     let lines = elem.lines.as_deref().unwrap_or_default();
 
+    if elem.block.get(styles)
+        && let Some(numbering) = elem.numbering.get_ref(styles)
+    {
+        return show_numbered_raw(elem, engine, styles, lines, numbering);
+    }
+
     let mut seq = EcoVec::with_capacity((2 * lines.len()).saturating_sub(1));
     for (i, line) in lines.iter().enumerate() {
         if i != 0 {
@@ -666,6 +742,49 @@ const RAW_RULE: ShowFn<RawElem> = |elem, _, styles| {
     Ok(realized)
 };
 
+/// Displays the lines of a raw block next to a column with their numbers.
+fn show_numbered_raw(
+    elem: &Packed<RawElem>,
+    engine: &mut Engine,
+    styles: StyleChain,
+    lines: &[Packed<RawLine>],
+    numbering: &Numbering,
+) -> SourceResult<Content> {
+    const COLUMN_GUTTER: Em = Em::new(1.0);
+
+    let span = elem.span();
+    let context = Context::new(None, Some(styles));
+    let align = elem.align.get(styles);
+
+    let mut cells = Vec::with_capacity(2 * lines.len());
+    for line in lines {
+        let number = numbering
+            .apply(engine, context.track(), line.span(), &[line.number.max(0) as u64])?
+            .display()
+            .aligned(HAlignment::End.into());
+        cells.push(GridChild::Item(GridItem::Cell(
+            Packed::new(GridCell::new(number)).spanned(span),
+        )));
+        cells.push(GridChild::Item(GridItem::Cell(
+            Packed::new(GridCell::new(line.clone().pack().aligned(align.into())))
+                .spanned(span),
+        )));
+    }
+
+    let grid = GridElem::new(cells)
+        .with_columns(TrackSizings(smallvec![Sizing::Auto, Sizing::Fr(Fr::one())]))
+        .with_column_gutter(TrackSizings(smallvec![COLUMN_GUTTER.into()]))
+        .with_row_gutter(TrackSizings(smallvec![styles.get(ParElem::leading).into()]));
+    let mut packed = Packed::new(grid).spanned(span);
+    packed.synthesize(engine, styles)?;
+
+    // Directly build the block element to avoid the show step for the grid
+    // element, like for the bibliography.
+    Ok(BlockElem::multi_layouter(packed, crate::grid::layout_grid)
+        .pack()
+        .spanned(span))
+}
+
 const RAW_LINE_RULE: ShowFn<RawLine> = |elem, _, _| Ok(elem.body.clone());
 
 const ALIGN_RULE: ShowFn<AlignElem> =
@@ -683,6 +802,10 @@ const STACK_RULE: ShowFn<StackElem> = |elem, _, _| {
     Ok(BlockElem::multi_layouter(elem.clone(), crate::stack::layout_stack).pack())
 };
 
+const OVERLAY_RULE: ShowFn<OverlayElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::overlay::layout_overlay).pack())
+};
+
 const GRID_RULE: ShowFn<GridElem> = |elem, _, _| {
     Ok(BlockElem::multi_layouter(elem.clone(), crate::grid::layout_grid).pack())
 };
@@ -729,6 +852,11 @@ const SKEW_RULE: ShowFn<SkewElem> = |elem, _, _| {
     Ok(BlockElem::single_layouter(elem.clone(), crate::transforms::layout_skew).pack())
 };
 
+const TRANSFORM_RULE: ShowFn<TransformElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::transforms::layout_transform)
+        .pack())
+};
+
 const REPEAT_RULE: ShowFn<RepeatElem> = |elem, _, _| {
     Ok(BlockElem::single_layouter(elem.clone(), crate::repeat::layout_repeat).pack())
 };
@@ -798,10 +926,22 @@ const POLYGON_RULE: ShowFn<PolygonElem> = |elem, _, _| {
     Ok(BlockElem::single_layouter(elem.clone(), crate::shapes::layout_polygon).pack())
 };
 
+const ARC_RULE: ShowFn<ArcElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::shapes::layout_arc).pack())
+};
+
 const CURVE_RULE: ShowFn<CurveElem> = |elem, _, _| {
     Ok(BlockElem::single_layouter(elem.clone(), crate::shapes::layout_curve).pack())
 };
 
+const OPACITY_RULE: ShowFn<OpacityElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::transforms::layout_opacity).pack())
+};
+
+const BLUR_RULE: ShowFn<BlurElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::transforms::layout_blur).pack())
+};
+
 const EQUATION_RULE: ShowFn<EquationElem> = |elem, _, styles| {
     if elem.block.get(styles) {
         Ok(BlockElem::multi_layouter(elem.clone(), crate::math::layout_equation_block)
@@ -9,9 +9,9 @@ use typst_library::layout::{
     Abs, Axes, Corner, Corners, Frame, FrameItem, Point, Ratio, Region, Rel, Sides, Size,
 };
 use typst_library::visualize::{
-    CircleElem, CloseMode, Curve, CurveComponent, CurveElem, EllipseElem, FillRule,
-    FixedStroke, Geometry, LineCap, LineElem, Paint, PolygonElem, RectElem, Shape,
-    SquareElem, Stroke,
+    ArcElem, CircleElem, CloseMode, Curve, CurveComponent, CurveElem, EllipseElem,
+    FillRule, FixedStroke, Geometry, LineCap, LineElem, LineMarker, LineMarkerKind,
+    Paint, PolygonElem, RectElem, Shadow, Shape, SquareElem, Stroke,
 };
 use typst_syntax::Span;
 use typst_utils::{Get, Numeric};
@@ -47,11 +47,101 @@ pub fn layout_line(
     }
 
     let mut frame = Frame::soft(size);
-    let shape = Geometry::Line(delta.to_point()).stroked(stroke);
-    frame.push(start.to_point(), FrameItem::Shape(shape, elem.span()));
+    let start_marker = elem.start_marker.get(styles);
+    let end_marker = elem.end_marker.get(styles);
+    let length = delta.to_point().hypot();
+    if length.is_zero() || (start_marker.is_none() && end_marker.is_none()) {
+        let shape = Geometry::Line(delta.to_point()).stroked(stroke);
+        frame.push(start.to_point(), FrameItem::Shape(shape, elem.span()));
+        return Ok(frame);
+    }
+
+    // The unit vector pointing from the start to the end of the line.
+    let dir = Axes::new(delta.x / length, delta.y / length);
+    let mut from = start.to_point();
+    let mut to = (start + delta).to_point();
+
+    // Filled arrowheads hide the end of the line, so that the line's
+    // cap doesn't poke out of the tip.
+    if let Some(marker) = start_marker {
+        let back = layout_line_marker(
+            &mut frame,
+            marker,
+            from,
+            dir.map(|v| -v),
+            &stroke,
+            elem,
+            styles,
+        );
+        from += Point::new(dir.x * back, dir.y * back);
+    }
+    if let Some(marker) = end_marker {
+        let back = layout_line_marker(&mut frame, marker, to, dir, &stroke, elem, styles);
+        to -= Point::new(dir.x * back, dir.y * back);
+    }
+
+    let shape = Geometry::Line(to - from).stroked(stroke);
+    frame.insert(0, from, FrameItem::Shape(shape, elem.span()));
     Ok(frame)
 }
 
+/// Layout a marker at the given tip of a line, pointing in the direction
+/// `dir`.
+///
+/// Returns how far the line should be shortened at the tip.
+fn layout_line_marker(
+    frame: &mut Frame,
+    marker: LineMarker,
+    tip: Point,
+    dir: Axes<f64>,
+    stroke: &FixedStroke,
+    elem: &Packed<LineElem>,
+    styles: StyleChain,
+) -> Abs {
+    let size = marker
+        .size
+        .resolve(styles)
+        .unwrap_or_else(|| Abs::pt(3.0) + 3.0 * stroke.thickness);
+
+    // A point `along` the line back from the tip and `across` it.
+    let at = |along: Abs, across: Abs| {
+        tip + Point::new(-dir.x * along - dir.y * across, -dir.y * along + dir.x * across)
+    };
+
+    let width = 0.4 * size;
+    let (shape, back) = match marker.kind {
+        LineMarkerKind::Arrow => {
+            let mut curve = Curve::new();
+            curve.move_(tip);
+            curve.line(at(size, width));
+            curve.line(at(size, -width));
+            curve.close();
+            (Geometry::Curve(curve).filled(stroke.paint.clone()), size / 2.0)
+        }
+        LineMarkerKind::Chevron => {
+            let mut curve = Curve::new();
+            curve.move_(at(size, width));
+            curve.line(tip);
+            curve.line(at(size, -width));
+            (Geometry::Curve(curve).stroked(stroke.clone()), Abs::zero())
+        }
+        LineMarkerKind::Circle => {
+            let mut curve = Curve::ellipse(Size::splat(size));
+            curve.translate(tip - Point::new(size / 2.0, size / 2.0));
+            (Geometry::Curve(curve).filled(stroke.paint.clone()), Abs::zero())
+        }
+        LineMarkerKind::Bar => {
+            let mut curve = Curve::new();
+            curve.move_(at(Abs::zero(), size / 2.0));
+            curve.line(at(Abs::zero(), -size / 2.0));
+            (Geometry::Curve(curve).stroked(stroke.clone()), Abs::zero())
+        }
+    };
+
+    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    back
+}
+
 /// Layout the curve.
 #[typst_macros::time(span = elem.span())]
 pub fn layout_curve(
@@ -357,6 +447,55 @@ pub fn layout_polygon(
     Ok(frame)
 }
 
+/// Layout the arc.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_arc(
+    elem: &Packed<ArcElem>,
+    _: &mut Engine,
+    _: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let radius = elem.radius.get(styles).0.resolve(styles);
+    if !radius.is_finite() {
+        bail!(elem.span(), "cannot create arc with infinite size");
+    }
+    if radius.any(|r| *r < Abs::zero()) {
+        bail!(elem.span(), "cannot create arc with negative radius");
+    }
+
+    // The arc takes up the space of its full ellipse.
+    let mut frame = Frame::soft(2.0 * radius);
+    let center = radius.to_point();
+
+    // Prepare fill and stroke.
+    let fill = elem.fill.get_cloned(styles);
+    let stroke = match elem.stroke.resolve(styles) {
+        Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
+        Smart::Auto => None,
+        Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+    };
+
+    let start = elem.start.get(styles);
+    let stop = elem.stop.get(styles);
+    let mut curve = Curve::arc(center, radius, start, stop);
+    if elem.pie.get(styles) {
+        curve.line(center);
+        curve.close();
+    } else if fill.is_some() {
+        curve.close();
+    }
+
+    let shape = Shape {
+        geometry: Geometry::Curve(curve),
+        stroke,
+        fill,
+        fill_rule: FillRule::default(),
+    };
+    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    Ok(frame)
+}
+
 /// Lay out the rectangle.
 #[typst_macros::time(span = elem.span())]
 pub fn layout_rect(
@@ -378,6 +517,7 @@ pub fn layout_rect(
         elem.inset.resolve(styles),
         elem.outset.resolve(styles),
         elem.radius.resolve(styles),
+        elem.shadow.get_cloned(styles),
         elem.span(),
     )
 }
@@ -403,6 +543,7 @@ pub fn layout_square(
         elem.inset.resolve(styles),
         elem.outset.resolve(styles),
         elem.radius.resolve(styles),
+        elem.shadow.get_cloned(styles),
         elem.span(),
     )
 }
@@ -428,6 +569,7 @@ pub fn layout_ellipse(
         elem.inset.resolve(styles),
         elem.outset.resolve(styles),
         Corners::splat(None),
+        elem.shadow.get_cloned(styles),
         elem.span(),
     )
 }
@@ -453,6 +595,7 @@ pub fn layout_circle(
         elem.inset.resolve(styles),
         elem.outset.resolve(styles),
         Corners::splat(None),
+        elem.shadow.get_cloned(styles),
         elem.span(),
     )
 }
@@ -496,6 +639,7 @@ fn layout_shape(
     inset: Sides<Option<Rel<Abs>>>,
     outset: Sides<Option<Rel<Abs>>>,
     radius: Corners<Option<Rel<Abs>>>,
+    shadow: Option<Shadow>,
     span: Span,
 ) -> SourceResult<Frame> {
     let mut frame;
@@ -592,9 +736,81 @@ fn layout_shape(
         }
     }
 
+    // Add the shadow behind everything else.
+    if let Some(shadow) = shadow {
+        let outset = outset.unwrap_or_default();
+        if kind.is_round() {
+            let outset = outset.relative_to(frame.size());
+            let size = frame.size() + outset.sum_by_axis();
+            add_shadow(&mut frame, &shadow, styles, span, |grow| {
+                let mut curve = Curve::ellipse(size + Size::splat(2.0 * grow));
+                curve.translate(Point::new(-outset.left - grow, -outset.top - grow));
+                curve
+            });
+        } else {
+            shadow_rect(
+                &mut frame,
+                &shadow,
+                &outset,
+                &radius.unwrap_or_default(),
+                styles,
+                span,
+            );
+        }
+    }
+
     Ok(frame)
 }
 
+/// Add a soft shadow with the outline of a rectangle with optional radius and
+/// outset behind the contents of the frame.
+pub fn shadow_rect(
+    frame: &mut Frame,
+    shadow: &Shadow,
+    outset: &Sides<Rel<Abs>>,
+    radius: &Corners<Rel<Abs>>,
+    styles: StyleChain,
+    span: Span,
+) {
+    let size = frame.size();
+    let none = Sides::splat(None);
+    add_shadow(frame, shadow, styles, span, |grow| {
+        clip_rect(size, radius, &none, &outset.map(|side| side + grow))
+    });
+}
+
+/// Add a soft shadow behind the contents of the frame.
+///
+/// The blur is approximated by layering translucent copies of the outline,
+/// each grown by a different amount. The `outline` function creates the
+/// outline grown by the given amount on each side.
+fn add_shadow(
+    frame: &mut Frame,
+    shadow: &Shadow,
+    styles: StyleChain,
+    span: Span,
+    outline: impl Fn(Abs) -> Curve,
+) {
+    let offset = Point::new(shadow.dx.resolve(styles), shadow.dy.resolve(styles));
+    let blur = shadow
+        .blur
+        .resolve(styles)
+        .clamp(Abs::zero(), frame.size().min_by_side());
+    let layers = (blur.to_pt().ceil() as usize).clamp(1, 16);
+
+    // Choose the alpha of each layer such that the layers add up to the
+    // shadow's alpha where all of them overlap. A fully opaque shadow would
+    // lose its blur, so we stay just below that.
+    let alpha = shadow.color.alpha().unwrap_or(1.0).min(0.99);
+    let color = shadow.color.with_alpha(1.0 - (1.0 - alpha).powf(1.0 / layers as f32));
+
+    frame.prepend_multiple((0..layers).map(|i| {
+        let grow = blur * ((i as f64 + 0.5) / layers as f64 - 0.5);
+        let shape = Geometry::Curve(outline(grow)).filled(color);
+        (offset, FrameItem::Shape(shape, span))
+    }));
+}
+
 /// Determines the forced size of a quadratic shape based on the region, if any.
 ///
 /// The size is forced if at least one axis is expanded because `expand` is
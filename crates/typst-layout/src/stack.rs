@@ -23,6 +23,7 @@ pub fn layout_stack(
         elem.span(),
         elem.spacing.get(styles),
         elem.dir.get(styles),
+        elem.baseline.get(styles),
         engine,
         locator,
         styles,
@@ -53,8 +54,8 @@ where
 }
 
 /// Layout multiple cells like a stack. Requires only the spacing to insert
-/// between blocks, the stack growth direction, its children, as well as
-/// relevant layout information.
+/// between blocks, the stack growth direction, whether to align children on
+/// their baselines, its children, as well as relevant layout information.
 ///
 /// In particular, this doesn't require creating a stack element explicitly, as
 /// it requires `Content`, which has restrictions as to which values it can
@@ -70,6 +71,7 @@ pub fn layout_stack_internal<'a, F>(
     span: Span,
     spacing: Option<Spacing>,
     dir: Dir,
+    baseline: bool,
     engine: &mut Engine,
     locator: Locator,
     styles: StyleChain,
@@ -78,7 +80,7 @@ pub fn layout_stack_internal<'a, F>(
 where
     F: Fn(&mut Engine, StyleChain, Regions) -> SourceResult<Fragment>,
 {
-    let mut layouter = StackLayouter::new(span, dir, locator, styles, regions);
+    let mut layouter = StackLayouter::new(span, dir, baseline, locator, styles, regions);
     let axis = layouter.dir.axis();
     let mut deferred = None;
 
@@ -132,6 +134,9 @@ struct StackLayouter<'a> {
     dir: Dir,
     /// The axis of the stacking direction.
     axis: Axis,
+    /// Whether to align the children of a horizontal stack on their
+    /// baselines.
+    baseline: bool,
     /// Provides unique locations to the stack's children.
     locator: SplitLocator<'a>,
     /// The inherited styles.
@@ -168,6 +173,7 @@ impl<'a> StackLayouter<'a> {
     fn new(
         span: Span,
         dir: Dir,
+        baseline: bool,
         locator: Locator<'a>,
         styles: StyleChain<'a>,
         mut regions: Regions<'a>,
@@ -182,6 +188,7 @@ impl<'a> StackLayouter<'a> {
             span,
             dir,
             axis,
+            baseline: baseline && axis == Axis::X,
             locator: locator.split(),
             styles,
             regions,
@@ -301,6 +308,21 @@ impl<'a> StackLayouter<'a> {
 
     /// Advance to the next region.
     fn finish_region(&mut self) -> SourceResult<()> {
+        // When aligning on baselines, the cross size is given by the largest
+        // ascent and descent instead of the tallest child.
+        let ascent = self.baseline.then(|| {
+            let mut ascent = Abs::zero();
+            let mut descent = Abs::zero();
+            for item in &self.items {
+                if let StackItem::Frame(frame, _) = item {
+                    ascent.set_max(frame.baseline());
+                    descent.set_max(frame.height() - frame.baseline());
+                }
+            }
+            self.used.cross = ascent + descent;
+            ascent
+        });
+
         // Determine the size of the stack in this region depending on whether
         // the region expands.
         let mut size = self
@@ -348,9 +370,12 @@ impl<'a> StackLayouter<'a> {
 
                     // Align along the cross axis.
                     let other = self.axis.other();
-                    let cross = align
-                        .get(other)
-                        .position(size.get(other) - frame.size().get(other));
+                    let cross = match ascent {
+                        Some(ascent) => ascent - frame.baseline(),
+                        None => align
+                            .get(other)
+                            .position(size.get(other) - frame.size().get(other)),
+                    };
 
                     let pos = GenericSize::new(cross, main).to_point(self.axis);
                     cursor += child;
@@ -359,6 +384,10 @@ impl<'a> StackLayouter<'a> {
             }
         }
 
+        if let Some(ascent) = ascent {
+            output.set_baseline(ascent);
+        }
+
         // Advance to the next region.
         self.regions.next();
         self.initial = self.regions.size;
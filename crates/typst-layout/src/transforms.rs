@@ -6,8 +6,9 @@ use typst_library::foundations::{Content, Packed, Resolve, Smart, StyleChain};
 use typst_library::introspection::Locator;
 use typst_library::layout::{
     Abs, Axes, FixedAlignment, Frame, MoveElem, Point, Ratio, Region, Rel, RotateElem,
-    ScaleAmount, ScaleElem, Size, SkewElem, Transform,
+    ScaleAmount, ScaleElem, Size, SkewElem, Transform, TransformElem,
 };
+use typst_library::visualize::{BlurElem, OpacityElem};
 use typst_utils::Numeric;
 
 /// Layout the moved content.
@@ -26,6 +27,40 @@ pub fn layout_move(
     Ok(frame)
 }
 
+/// Layout the translucent content.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_opacity(
+    elem: &Packed<OpacityElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let amount = elem.amount.get(styles).0;
+    let mut frame = crate::layout_frame(engine, &elem.body, locator, styles, region)?;
+    frame.opacity(amount);
+    Ok(frame)
+}
+
+/// Layout the blurred content.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_blur(
+    elem: &Packed<BlurElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let radius = elem.radius.resolve(styles);
+    if radius < Abs::zero() {
+        bail!(elem.span(), "blur radius must not be negative");
+    }
+
+    let mut frame = crate::layout_frame(engine, &elem.body, locator, styles, region)?;
+    frame.blur(radius);
+    Ok(frame)
+}
+
 /// Layout the rotated content.
 #[typst_macros::time(span = elem.span())]
 pub fn layout_rotate(
@@ -184,6 +219,43 @@ pub fn layout_skew(
     )
 }
 
+/// Layout the content with an arbitrary affine transformation.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_transform(
+    elem: &Packed<TransformElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let transform = elem.matrix.resolve(styles);
+    let align = elem.origin.resolve(styles);
+
+    // Compute the new region's approximate size. A singular matrix collapses
+    // the content, so any size is fine.
+    let size = match transform.invert() {
+        Some(inverse) if region.size.is_finite() => {
+            compute_bounding_box(region.size, inverse).1
+        }
+        _ => Size::splat(Abs::inf()),
+    };
+
+    measure_and_layout(
+        engine,
+        locator,
+        region,
+        size,
+        styles,
+        &elem.body,
+        transform,
+        align,
+        elem.reflow.get(styles),
+        // As for scaling, don't preserve the baseline if the content is
+        // flipped vertically.
+        transform.sy.get() >= 0.0,
+    )
+}
+
 /// Applies a transformation to a frame, reflowing the layout if necessary.
 #[allow(clippy::too_many_arguments)]
 fn measure_and_layout(
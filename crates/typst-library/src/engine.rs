@@ -9,7 +9,7 @@ use rustc_hash::FxHashSet;
 use typst_syntax::{FileId, Span};
 use typst_utils::{LazyHash, Protected};
 
-use crate::diag::{HintedStrResult, SourceDiagnostic, SourceResult, StrResult, bail};
+use crate::diag::{HintedStrResult, SourceDiagnostic, SourceResult, bail};
 use crate::foundations::{Styles, Value};
 use crate::introspection::{Introspect, Introspection, Introspector};
 use crate::{Library, World};
@@ -337,22 +337,18 @@ impl<'a> Route<'a> {
 /// call checks are interleaved, for show rule problems we always get the show
 /// rule error. The lower the max depth for a kind of error, the higher its
 /// precedence compared to the others.
+///
+/// The show rule and function call depths are configurable through [`DepthLimits`].
 impl Route<'_> {
-    /// The maximum stack nesting depth.
-    const MAX_SHOW_RULE_DEPTH: usize = 64;
-
     /// The maximum layout nesting depth.
     const MAX_LAYOUT_DEPTH: usize = 72;
 
     /// The maximum HTML nesting depth.
     const MAX_HTML_DEPTH: usize = 72;
 
-    /// The maximum function call nesting depth.
-    const MAX_CALL_DEPTH: usize = 80;
-
     /// Ensures that we are within the maximum show rule depth.
-    pub fn check_show_depth(&self) -> HintedStrResult<()> {
-        if !self.within(Route::MAX_SHOW_RULE_DEPTH) {
+    pub fn check_show_depth(&self, limits: &DepthLimits) -> HintedStrResult<()> {
+        if !self.within(limits.show_rule_depth) {
             bail!(
                 "maximum show rule depth exceeded";
                 hint: "maybe a show rule matches its own output";
@@ -385,14 +381,58 @@ impl Route<'_> {
     }
 
     /// Ensures that we are within the maximum function call depth.
-    pub fn check_call_depth(&self) -> StrResult<()> {
-        if !self.within(Route::MAX_CALL_DEPTH) {
-            bail!("maximum function call depth exceeded");
+    pub fn check_call_depth(&self, limits: &DepthLimits) -> HintedStrResult<()> {
+        if !self.within(limits.call_depth) {
+            bail!(
+                "maximum function call depth exceeded";
+                hint: "maybe a recursive function is missing a base case";
+                hint: "the limit is {} nested calls", limits.call_depth;
+            );
         }
         Ok(())
     }
 }
 
+/// Configurable nesting limits for compilation.
+///
+/// The show rule depth should stay below the function call depth. Otherwise, a
+/// show rule that matches its own output may be reported as a function call
+/// depth error.
+///
+/// The limits protect against runaway recursion before the native stack runs
+/// out. Raising them far above the defaults can thus make deeply recursive
+/// documents crash the whole process with a stack overflow instead of failing
+/// with an error. How much headroom there is depends on the stack size of the
+/// compiling thread.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct DepthLimits {
+    /// The maximum function call nesting depth.
+    pub call_depth: usize,
+    /// The maximum show rule nesting depth.
+    pub show_rule_depth: usize,
+}
+
+impl DepthLimits {
+    /// Configure the maximum function call nesting depth.
+    pub fn with_call_depth(mut self, depth: usize) -> Self {
+        self.call_depth = depth;
+        self
+    }
+
+    /// Configure the maximum show rule nesting depth.
+    pub fn with_show_rule_depth(mut self, depth: usize) -> Self {
+        self.show_rule_depth = depth;
+        self
+    }
+}
+
+impl Default for DepthLimits {
+    fn default() -> Self {
+        Self { call_depth: 80, show_rule_depth: 64 }
+    }
+}
+
 #[comemo::track]
 #[allow(clippy::needless_lifetimes)]
 impl<'a> Route<'a> {
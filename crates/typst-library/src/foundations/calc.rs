@@ -718,26 +718,44 @@ pub fn lcm(
 ///
 /// If the number is already an integer, it is returned unchanged.
 ///
-/// Note that this function will always return an @int[integer], and will error
-/// if the resulting @float or @decimal is larger than the maximum 64-bit signed
-/// integer or smaller than the minimum for that type.
+/// Note that without `digits`, this function will always return an
+/// @int[integer], and will error if the resulting @float or @decimal is larger
+/// than the maximum 64-bit signed integer or smaller than the minimum for that
+/// type.
 ///
 /// ```example
 /// #calc.floor(500.1)
 /// #assert(calc.floor(3) == 3)
 /// #assert(calc.floor(3.14) == 3)
 /// #assert(calc.floor(decimal("-3.14")) == -4)
+/// #assert(calc.floor(3.1415, digits: 2) == 3.14)
+/// #assert(calc.floor(1299, digits: -2) == 1200)
 /// ```
 #[func]
 pub fn floor(
     /// The number to round down.
     value: DecNum,
-) -> StrResult<i64> {
+    /// If given, the number of decimal places to keep instead of rounding to
+    /// an integer. The result then has the same type as the number, like with
+    /// @calc.round.
+    ///
+    /// If negative, the number of significant integer digits that should be
+    /// removed before the decimal point.
+    #[named]
+    digits: Option<i64>,
+) -> StrResult<DecNum> {
+    if let Some(digits) = digits {
+        return Rounding::Down.apply(value, digits);
+    }
     match value {
-        DecNum::Int(n) => Ok(n),
-        DecNum::Float(n) => Ok(crate::foundations::convert_float_to_int(n.floor())
-            .map_err(|_| too_large())?),
-        DecNum::Decimal(n) => Ok(i64::try_from(n.floor()).map_err(|_| too_large())?),
+        DecNum::Int(n) => Ok(DecNum::Int(n)),
+        DecNum::Float(n) => Ok(DecNum::Int(
+            crate::foundations::convert_float_to_int(n.floor())
+                .map_err(|_| too_large())?,
+        )),
+        DecNum::Decimal(n) => {
+            Ok(DecNum::Int(i64::try_from(n.floor()).map_err(|_| too_large())?))
+        }
     }
 }
 
@@ -745,26 +763,44 @@ pub fn floor(
 ///
 /// If the number is already an integer, it is returned unchanged.
 ///
-/// Note that this function will always return an @int[integer], and will error
-/// if the resulting @float or @decimal is larger than the maximum 64-bit signed
-/// integer or smaller than the minimum for that type.
+/// Note that without `digits`, this function will always return an
+/// @int[integer], and will error if the resulting @float or @decimal is larger
+/// than the maximum 64-bit signed integer or smaller than the minimum for that
+/// type.
 ///
 /// ```example
 /// #calc.ceil(500.1)
 /// #assert(calc.ceil(3) == 3)
 /// #assert(calc.ceil(3.14) == 4)
 /// #assert(calc.ceil(decimal("-3.14")) == -3)
+/// #assert(calc.ceil(3.1415, digits: 2) == 3.15)
+/// #assert(calc.ceil(1201, digits: -2) == 1300)
 /// ```
 #[func]
 pub fn ceil(
     /// The number to round up.
     value: DecNum,
-) -> StrResult<i64> {
+    /// If given, the number of decimal places to keep instead of rounding to
+    /// an integer. The result then has the same type as the number, like with
+    /// @calc.round.
+    ///
+    /// If negative, the number of significant integer digits that should be
+    /// removed before the decimal point.
+    #[named]
+    digits: Option<i64>,
+) -> StrResult<DecNum> {
+    if let Some(digits) = digits {
+        return Rounding::Up.apply(value, digits);
+    }
     match value {
-        DecNum::Int(n) => Ok(n),
-        DecNum::Float(n) => Ok(crate::foundations::convert_float_to_int(n.ceil())
-            .map_err(|_| too_large())?),
-        DecNum::Decimal(n) => Ok(i64::try_from(n.ceil()).map_err(|_| too_large())?),
+        DecNum::Int(n) => Ok(DecNum::Int(n)),
+        DecNum::Float(n) => Ok(DecNum::Int(
+            crate::foundations::convert_float_to_int(n.ceil())
+                .map_err(|_| too_large())?,
+        )),
+        DecNum::Decimal(n) => {
+            Ok(DecNum::Int(i64::try_from(n.ceil()).map_err(|_| too_large())?))
+        }
     }
 }
 
@@ -772,26 +808,43 @@ pub fn ceil(
 ///
 /// If the number is already an integer, it is returned unchanged.
 ///
-/// Note that this function will always return an @int[integer], and will error
-/// if the resulting @float or @decimal is larger than the maximum 64-bit signed
-/// integer or smaller than the minimum for that type.
+/// Note that without `digits`, this function will always return an
+/// @int[integer], and will error if the resulting @float or @decimal is larger
+/// than the maximum 64-bit signed integer or smaller than the minimum for that
+/// type.
 ///
 /// ```example
 /// #calc.trunc(15.9)
 /// #assert(calc.trunc(3) == 3)
 /// #assert(calc.trunc(-3.7) == -3)
 /// #assert(calc.trunc(decimal("8493.12949582390")) == 8493)
+/// #assert(calc.trunc(-3.1415, digits: 2) == -3.14)
 /// ```
 #[func(title = "Truncate")]
 pub fn trunc(
     /// The number to truncate.
     value: DecNum,
-) -> StrResult<i64> {
+    /// If given, the number of decimal places to keep instead of truncating to
+    /// an integer. The result then has the same type as the number, like with
+    /// @calc.round.
+    ///
+    /// If negative, the number of significant integer digits that should be
+    /// removed before the decimal point.
+    #[named]
+    digits: Option<i64>,
+) -> StrResult<DecNum> {
+    if let Some(digits) = digits {
+        return Rounding::Zero.apply(value, digits);
+    }
     match value {
-        DecNum::Int(n) => Ok(n),
-        DecNum::Float(n) => Ok(crate::foundations::convert_float_to_int(n.trunc())
-            .map_err(|_| too_large())?),
-        DecNum::Decimal(n) => Ok(i64::try_from(n.trunc()).map_err(|_| too_large())?),
+        DecNum::Int(n) => Ok(DecNum::Int(n)),
+        DecNum::Float(n) => Ok(DecNum::Int(
+            crate::foundations::convert_float_to_int(n.trunc())
+                .map_err(|_| too_large())?,
+        )),
+        DecNum::Decimal(n) => {
+            Ok(DecNum::Int(i64::try_from(n.trunc()).map_err(|_| too_large())?))
+        }
     }
 }
 
@@ -1248,6 +1301,97 @@ cast! {
     v: f64 => Self::Float(v),
 }
 
+/// The direction in which `floor`, `ceil`, and `trunc` round.
+#[derive(Debug, Copy, Clone)]
+enum Rounding {
+    Down,
+    Up,
+    Zero,
+}
+
+impl Rounding {
+    /// Rounds a number to the given amount of decimal places, or to integer
+    /// digits if `digits` is negative.
+    fn apply(self, value: DecNum, digits: i64) -> StrResult<DecNum> {
+        Ok(match value {
+            DecNum::Int(n) => DecNum::Int(self.int(n, digits).ok_or_else(too_large)?),
+            DecNum::Float(n) => DecNum::Float(self.float(n, digits.saturating_as())),
+            DecNum::Decimal(n) => DecNum::Decimal(
+                n.round_with_strategy(digits.saturating_as(), self.strategy())
+                    .ok_or_else(too_large)?,
+            ),
+        })
+    }
+
+    /// Rounds an integer to a multiple of `10^-digits`.
+    ///
+    /// Returns `None` if the result does not fit into an integer.
+    fn int(self, n: i64, digits: i64) -> Option<i64> {
+        if digits >= 0 {
+            return Some(n);
+        }
+
+        let Some(factor) = u32::try_from(digits.unsigned_abs())
+            .ok()
+            .and_then(|exp| 10i64.checked_pow(exp))
+        else {
+            // Larger than any possible amount of integer digits.
+            return match self {
+                Self::Down if n < 0 => None,
+                Self::Up if n > 0 => None,
+                _ => Some(0),
+            };
+        };
+
+        let (quotient, remainder) = (n / factor, n % factor);
+        let quotient = match self {
+            Self::Down if remainder < 0 => quotient - 1,
+            Self::Up if remainder > 0 => quotient + 1,
+            _ => quotient,
+        };
+
+        quotient.checked_mul(factor)
+    }
+
+    /// Rounds a float to the given amount of decimal places.
+    fn float(self, value: f64, digits: i16) -> f64 {
+        // Rounding beyond the representable precision has no effect.
+        if !value.is_finite() || digits >= f64::DIGITS as i16 {
+            return value;
+        }
+
+        let digits = digits.max(-(f64::MAX_10_EXP as i16));
+        let offset = libm::exp10(f64::from(digits.abs()));
+        let scaled = if digits >= 0 { value * offset } else { value / offset };
+
+        // Snap to the nearest integer if we are only off due to floating point
+        // imprecision. Otherwise, flooring `0.29` to two digits would result
+        // in `0.28` because `0.29 * 100.0` is slightly less than `29.0`. The
+        // imprecision is at most a few units in the last place, so the
+        // threshold scales with the magnitude of the number.
+        let nearest = scaled.round();
+        let tolerance = 4.0 * f64::EPSILON * scaled.abs();
+        let scaled = if (scaled - nearest).abs() <= tolerance { nearest } else { scaled };
+
+        let rounded = match self {
+            Self::Down => scaled.floor(),
+            Self::Up => scaled.ceil(),
+            Self::Zero => scaled.trunc(),
+        };
+
+        if digits >= 0 { rounded / offset } else { rounded * offset }
+    }
+
+    /// The equivalent rounding strategy for decimals.
+    fn strategy(self) -> rust_decimal::RoundingStrategy {
+        match self {
+            Self::Down => rust_decimal::RoundingStrategy::ToNegativeInfinity,
+            Self::Up => rust_decimal::RoundingStrategy::ToPositiveInfinity,
+            Self::Zero => rust_decimal::RoundingStrategy::ToZero,
+        }
+    }
+}
+
 /// A value which can be passed to functions that work with integers, floats,
 /// and decimals.
 #[derive(Debug, Copy, Clone)]
@@ -191,6 +191,11 @@ impl Datetime {
             return None;
         };
 
+        Self::parse_iso(string)
+    }
+
+    /// Try to parse an ISO 8601 date, time, or datetime.
+    fn parse_iso(string: &str) -> Option<Self> {
         if let Ok(d) = time::PrimitiveDateTime::parse(
             string,
             &format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]Z"),
@@ -354,6 +359,26 @@ impl Datetime {
         })
     }
 
+    /// Parses a datetime from an ISO 8601 string.
+    ///
+    /// Accepts a date (`{"2024-03-12"}`), a time (`{"14:30:00"}`), or both
+    /// separated by a `T` (`{"2024-03-12T14:30:00"}`), optionally followed by
+    /// a `Z`. Like with the constructor, Typst stores just a date, just a time,
+    /// or a full datetime depending on what the string contains.
+    ///
+    /// ```example
+    /// #let date = datetime.from-iso("2024-03-12")
+    /// #date.display("[day].[month].[year]")
+    /// ```
+    #[func(title = "From ISO 8601")]
+    pub fn from_iso(
+        /// The string to parse.
+        string: Str,
+    ) -> StrResult<Datetime> {
+        Self::parse_iso(&string)
+            .ok_or_else(|| eco_format!("invalid ISO 8601 datetime: {}", string.repr()))
+    }
+
     /// Returns the current date.
     ///
     /// In the CLI, this can be overridden with the `--creation-timestamp`
@@ -157,12 +157,24 @@ impl Decimal {
     /// Note that this can return `None` when using negative digits where the
     /// rounded number would overflow the available range for decimals.
     pub fn round(self, digits: i32) -> Option<Self> {
+        self.round_with_strategy(
+            digits,
+            rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        )
+    }
+
+    /// Rounds this decimal to the specified amount of digits with the given
+    /// rounding strategy.
+    ///
+    /// Negative digits are handled as in [`round`](Self::round).
+    pub(crate) fn round_with_strategy(
+        self,
+        digits: i32,
+        strategy: rust_decimal::RoundingStrategy,
+    ) -> Option<Self> {
         // Positive digits can be handled by just rounding with rust_decimal.
         if let Ok(positive_digits) = u32::try_from(digits) {
-            return Some(Self(self.0.round_dp_with_strategy(
-                positive_digits,
-                rust_decimal::RoundingStrategy::MidpointAwayFromZero,
-            )));
+            return Some(Self(self.0.round_dp_with_strategy(positive_digits, strategy)));
         }
 
         // We received negative digits, so we round to integer digits.
@@ -182,10 +194,7 @@ impl Decimal {
         };
 
         // Round to this integer digit.
-        num = num.round_dp_with_strategy(
-            0,
-            rust_decimal::RoundingStrategy::MidpointAwayFromZero,
-        );
+        num = num.round_dp_with_strategy(0, strategy);
 
         // Multiply by 10^digits again, which can overflow and fail.
         num.checked_mul(ten_to_digits).map(Self)
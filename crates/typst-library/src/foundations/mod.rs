@@ -27,6 +27,7 @@ mod none;
 mod path;
 #[path = "plugin.rs"]
 mod plugin_;
+mod random;
 mod scope;
 mod selector;
 mod str;
@@ -58,6 +59,7 @@ pub use self::module::*;
 pub use self::none::*;
 pub use self::path::*;
 pub use self::plugin_::*;
+pub use self::random::*;
 pub use self::repr::Repr;
 pub use self::scope::*;
 pub use self::selector::*;
@@ -110,6 +112,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict) {
     global.define_type::<Symbol>();
     global.define_type::<Duration>();
     global.define_type::<Version>();
+    global.define_type::<Random>();
     global.define_type::<RootedPath>();
     global.define_func::<repr::repr>();
     global.define_func::<panic>();
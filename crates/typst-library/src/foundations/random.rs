@@ -0,0 +1,148 @@
+use ecow::{EcoString, eco_format};
+
+use crate::diag::{StrResult, bail};
+use crate::foundations::{Array, Repr, Value, array, func, scope, ty};
+
+/// A deterministic pseudo-random number generator.
+///
+/// A generator is created from a seed. The same seed always produces the same
+/// sequence of numbers, so documents using random numbers still compile
+/// reproducibly. This is useful for exam generators or generative art.
+///
+/// Like all values in Typst, a generator cannot be modified. Instead, each
+/// method returns an array with an advanced generator and the generated value.
+/// Use the advanced generator for the next call to get a different value.
+///
+/// = Example <example>
+/// ```example
+/// #let rng = random(seed: 42)
+/// #let (rng, die) = rng.int(1, 7)
+/// #let (rng, coin) = rng.pick(("heads", "tails"))
+/// #let (rng, order) = rng.shuffle((1, 2, 3, 4, 5))
+///
+/// Die: #die \
+/// Coin: #coin \
+/// Order: #order.map(str).join(", ")
+/// ```
+#[ty(scope)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    /// Create a generator from a seed.
+    pub fn new(seed: i64) -> Self {
+        Self { state: seed as u64 }
+    }
+
+    /// Generate the next 64 random bits with the SplitMix64 algorithm.
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Generate a number in `0..bound` without modulo bias.
+    fn below(&mut self, bound: u64) -> u64 {
+        let zone = u64::MAX - (u64::MAX - bound + 1) % bound;
+        loop {
+            let bits = self.next();
+            if bits <= zone {
+                return bits % bound;
+            }
+        }
+    }
+}
+
+#[scope]
+impl Random {
+    /// Creates a new random number generator.
+    #[func(constructor)]
+    pub fn construct(
+        /// The seed of the generator.
+        #[named]
+        #[default(0)]
+        seed: i64,
+    ) -> Random {
+        Random::new(seed)
+    }
+
+    /// Generates a random integer between `low` (inclusive) and `high`
+    /// (exclusive).
+    ///
+    /// Returns an array with the advanced generator and the integer.
+    #[func]
+    pub fn int(
+        self,
+        /// The smallest integer that can be generated.
+        low: i64,
+        /// The integer above the largest one that can be generated.
+        high: i64,
+    ) -> StrResult<Array> {
+        if low >= high {
+            bail!("range must not be empty (low: {low}, high: {high})");
+        }
+        let mut rng = self;
+        let span = high.wrapping_sub(low) as u64;
+        let value = low.wrapping_add(rng.below(span) as i64);
+        Ok(array![rng, value])
+    }
+
+    /// Generates a random float between `{0.0}` (inclusive) and `{1.0}`
+    /// (exclusive).
+    ///
+    /// Returns an array with the advanced generator and the float.
+    #[func]
+    pub fn float(self) -> Array {
+        let mut rng = self;
+        let value = (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+        array![rng, value]
+    }
+
+    /// Picks a random item from an array.
+    ///
+    /// Returns an array with the advanced generator and the item. Fails with
+    /// an error if the array is empty.
+    #[func]
+    pub fn pick(
+        self,
+        /// The array to pick from.
+        array: Array,
+    ) -> StrResult<Array> {
+        if array.is_empty() {
+            bail!("cannot pick from an empty array");
+        }
+        let mut rng = self;
+        let index = rng.below(array.len() as u64) as usize;
+        let item = array.as_slice()[index].clone();
+        Ok(array![rng, item])
+    }
+
+    /// Shuffles the items of an array.
+    ///
+    /// Returns an array with the advanced generator and the shuffled array.
+    #[func]
+    pub fn shuffle(
+        self,
+        /// The array to shuffle.
+        array: Array,
+    ) -> Array {
+        // Fisher-Yates shuffle.
+        let mut rng = self;
+        let mut items: Vec<Value> = array.into_iter().collect();
+        for i in (1..items.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+        array![rng, items.into_iter().collect::<Array>()]
+    }
+}
+
+impl Repr for Random {
+    fn repr(&self) -> EcoString {
+        eco_format!("random(..)")
+    }
+}
@@ -6,7 +6,7 @@ use std::ops::{Add, AddAssign, Deref, Range};
 use comemo::Tracked;
 use ecow::EcoString;
 use serde::{Deserialize, Serialize};
-use typst_syntax::Spanned;
+use typst_syntax::{Span, Spanned};
 use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -138,6 +138,10 @@ impl Str {
     /// - From labels the name is extracted.
     /// - Bytes are decoded as UTF-8.
     ///
+    /// Integers and floats can additionally be formatted with a fixed
+    /// precision, zero padding, digit group separators, a custom decimal
+    /// separator, or in scientific notation.
+    ///
     /// If you wish to convert from and to Unicode code points, see the
     /// @str.to-unicode[`to-unicode`] and @str.from-unicode[`from-unicode`]
     /// functions.
@@ -147,24 +151,123 @@ impl Str {
     /// #str(4000, base: 16) \
     /// #str(2.7) \
     /// #str(1e8) \
-    /// #str(<intro>)
+    /// #str(<intro>) \
+    /// #str(1234567.891, precision: 2, thousands: ",") \
+    /// #str(7, digits: 3) \
+    /// #str(12345.678, precision: 2, scientific: true)
     /// ```
     #[func(constructor)]
     pub fn construct(
+        span: Span,
         /// The value that should be converted to a string.
         value: ToStr,
         /// The base (radix) to display integers in, between 2 and 36.
         #[named]
         #[default(Spanned::detached(Base::Default))]
         base: Spanned<Base>,
+        /// The number of digits to display after the decimal separator.
+        ///
+        /// Floats are rounded to this precision and integers are padded with
+        /// trailing zeros. If `{none}`, floats are displayed with as many
+        /// digits as necessary and integers without a fractional part.
+        ///
+        /// The precision can be at most 340, which is enough to display the
+        /// significant digits of any float. In scientific notation, it can be
+        /// at most 17, which is enough to display any float without loss.
+        ///
+        /// ```example
+        /// #str(3.14159, precision: 2) \
+        /// #str(5, precision: 2)
+        /// ```
+        #[named]
+        precision: Option<Spanned<usize>>,
+        /// The minimum number of digits before the decimal separator. Numbers
+        /// with fewer digits are padded with leading zeros.
+        ///
+        /// The number of digits can be at most 64, which is the length of the
+        /// largest integer in base 2.
+        ///
+        /// ```example
+        /// #str(42, digits: 5) \
+        /// #str(255, base: 16, digits: 4)
+        /// ```
+        #[named]
+        digits: Option<Spanned<usize>>,
+        /// A separator to insert between groups of three digits before the
+        /// decimal separator.
+        ///
+        /// ```example
+        /// #str(1000000, thousands: ",") \
+        /// #str(1234.5, thousands: "\u{202F}")
+        /// ```
+        #[named]
+        thousands: Option<Str>,
+        /// The separator between the integer and the fractional part. If
+        /// `{none}`, a period is used.
+        ///
+        /// ```example
+        /// #str(1234.5, decimal: ",", thousands: ".")
+        /// ```
+        #[named]
+        decimal: Option<Str>,
+        /// Whether to display the number in scientific notation, with a single
+        /// digit before the decimal separator.
+        ///
+        /// ```example
+        /// #str(6.022e23, scientific: true) \
+        /// #str(0.00125, precision: 1, scientific: true)
+        /// ```
+        #[named]
+        #[default(false)]
+        scientific: bool,
     ) -> SourceResult<Str> {
+        if let Some(Spanned { v, span }) = precision {
+            if scientific && v > NumberFormat::MAX_SCIENTIFIC_PRECISION {
+                bail!(
+                    span,
+                    "precision must be at most {} in scientific notation",
+                    NumberFormat::MAX_SCIENTIFIC_PRECISION,
+                );
+            } else if v > NumberFormat::MAX_PRECISION {
+                bail!(span, "precision must be at most {}", NumberFormat::MAX_PRECISION);
+            }
+        }
+        if let Some(Spanned { v, span }) = digits
+            && v > NumberFormat::MAX_DIGITS
+        {
+            bail!(span, "digits must be at most {}", NumberFormat::MAX_DIGITS);
+        }
+
+        let format = NumberFormat {
+            precision: precision.map(|p| p.v),
+            digits: digits.map(|d| d.v),
+            thousands,
+            decimal,
+            scientific,
+        };
         Ok(match value {
             ToStr::Str(s) => {
                 if matches!(base.v, Base::User(_)) {
                     bail!(base.span, "base is only supported for integers");
                 }
+                if !format.is_plain() {
+                    bail!(
+                        span,
+                        "number formatting is only supported for integers and floats"
+                    );
+                }
                 s
             }
+            ToStr::Float(v) => {
+                if matches!(base.v, Base::User(_)) {
+                    bail!(base.span, "base is only supported for integers");
+                }
+                if format.is_plain() {
+                    repr::display_float(v).into()
+                } else {
+                    format.float(v).into()
+                }
+            }
             ToStr::Int(n) => {
                 let b = base.v.value();
                 if b == 1 && n > 0 {
@@ -176,7 +279,19 @@ impl Str {
                 if b < 2 || b > 36 {
                     bail!(base.span, "base must be between 2 and 36");
                 }
-                repr::format_int_with_base(n, b).into()
+                if format.is_plain() {
+                    repr::format_int_with_base(n, b).into()
+                } else if format.scientific {
+                    if b != 10 {
+                        bail!(
+                            base.span,
+                            "scientific notation is only supported in base 10"
+                        );
+                    }
+                    format.float(n as f64).into()
+                } else {
+                    format.apply(&repr::format_int_with_base(n, b)).into()
+                }
             }
         })
     }
@@ -860,12 +975,14 @@ pub enum ToStr {
     Str(Str),
     /// An integer about to be formatted in a given base.
     Int(i64),
+    /// A float about to be formatted.
+    Float(f64),
 }
 
 cast! {
     ToStr,
     v: i64 => Self::Int(v),
-    v: f64 => Self::Str(repr::display_float(v).into()),
+    v: f64 => Self::Float(v),
     v: Decimal => Self::Str(format_str!("{}", v)),
     v: Version => Self::Str(format_str!("{}", v)),
     v: Bytes => Self::Str(v.to_str().map_err(|_| "bytes are not valid UTF-8")?),
@@ -874,6 +991,120 @@ cast! {
     v: Str => Self::Str(v),
 }
 
+/// Options for formatting a number in the `str` constructor.
+struct NumberFormat {
+    precision: Option<usize>,
+    digits: Option<usize>,
+    thousands: Option<Str>,
+    decimal: Option<Str>,
+    scientific: bool,
+}
+
+impl NumberFormat {
+    /// The maximum precision. This bounds the size of the formatted number.
+    /// It suffices for 17 significant digits of the smallest subnormal float,
+    /// `5e-324`.
+    const MAX_PRECISION: usize = 340;
+
+    /// The maximum precision in scientific notation. With 17 significant
+    /// digits, every float can be displayed without loss.
+    const MAX_SCIENTIFIC_PRECISION: usize = 17;
+
+    /// The maximum number of digits before the decimal separator, which is
+    /// the length of `i64::MIN` in base 2.
+    const MAX_DIGITS: usize = 64;
+
+    /// Whether no formatting option was set.
+    fn is_plain(&self) -> bool {
+        self.precision.is_none()
+            && self.digits.is_none()
+            && self.thousands.is_none()
+            && self.decimal.is_none()
+            && !self.scientific
+    }
+
+    /// Formats a float according to the options.
+    fn float(&self, v: f64) -> EcoString {
+        if !v.is_finite() {
+            return repr::display_float(v);
+        }
+
+        let text = match (self.precision, self.scientific) {
+            (Some(p), true) => format!("{v:.p$e}"),
+            (None, true) => format!("{v:e}"),
+            (Some(p), false) => format!("{v:.p$}"),
+            (None, false) => format!("{v}"),
+        };
+
+        self.apply(&text)
+    }
+
+    /// Applies padding and separators to an already formatted number.
+    ///
+    /// The number may start with a minus sign and may contain a period and an
+    /// exponent.
+    fn apply(&self, text: &str) -> EcoString {
+        let (mantissa, exponent) = match text.split_once('e') {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (text, None),
+        };
+
+        let (negative, mantissa) = match mantissa
+            .strip_prefix('-')
+            .or_else(|| mantissa.strip_prefix(repr::MINUS_SIGN))
+        {
+            Some(rest) => (true, rest),
+            None => (false, mantissa),
+        };
+
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let mut out = EcoString::new();
+
+        // Rounding may have turned a small negative number into zero.
+        if negative && mantissa.chars().any(|c| c.is_ascii_alphanumeric() && c != '0') {
+            out.push_str(repr::MINUS_SIGN);
+        }
+
+        let padding = self.digits.unwrap_or(0).saturating_sub(int.len());
+        let int = "0".repeat(padding) + int;
+        for (i, c) in int.chars().enumerate() {
+            if i > 0
+                && (int.len() - i) % 3 == 0
+                && let Some(sep) = &self.thousands
+            {
+                out.push_str(sep);
+            }
+            out.push(c);
+        }
+
+        let mut frac = EcoString::from(frac);
+        if let Some(p) = self.precision {
+            while frac.len() < p {
+                frac.push('0');
+            }
+        }
+
+        if !frac.is_empty() {
+            out.push_str(self.decimal.as_deref().unwrap_or("."));
+            out.push_str(&frac);
+        }
+
+        if let Some(exponent) = exponent {
+            out.push('e');
+            match exponent.strip_prefix('-') {
+                Some(rest) => {
+                    out.push_str(repr::MINUS_SIGN);
+                    out.push_str(rest);
+                }
+                None => out.push_str(exponent),
+            }
+        }
+
+        out
+    }
+}
+
 /// Similar to `Option<i64>`, but the default value casts to `10` rather than
 /// `none`, so that the right default value is documented.
 #[derive(Debug, Copy, Clone)]
@@ -9,7 +9,7 @@ use crate::layout::{
     Abs, Corners, Em, Fr, Fragment, Frame, Length, Region, Regions, Rel, Sides, Size,
     Spacing, VAlignment,
 };
-use crate::visualize::{Paint, Stroke};
+use crate::visualize::{Paint, Shadow, Stroke};
 
 /// An inline-level container that sizes content.
 ///
@@ -103,6 +103,9 @@ pub struct BoxElem {
     /// outsets are different from relative @box.width[widths] and
     /// @box.height[heights], which are relative to the container.
     ///
+    /// Insets may be negative, in which case the content extends beyond the
+    /// box's bounds.
+    ///
     /// _Note:_ When the box contains text, its exact size depends on the
     /// current @text.top-edge[text edges].
     ///
@@ -135,6 +138,34 @@ pub struct BoxElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A soft shadow to draw behind the box.
+    ///
+    /// Can be a color or a dictionary with the following keys, all of which
+    /// are optional:
+    ///
+    /// - `dx`: The horizontal offset of the shadow. Defaults to `{2pt}`.
+    /// - `dy`: The vertical offset of the shadow. Defaults to `{2pt}`.
+    /// - `blur`: How far the shadow's edge fades out. Defaults to `{4pt}`.
+    /// - `color`: The shadow's color. Defaults to a translucent black. The
+    ///   blur works best with translucent colors.
+    ///
+    /// The shadow follows the box's radius and outset.
+    ///
+    /// ```example
+    /// #box(
+    ///   fill: white,
+    ///   inset: 8pt,
+    ///   radius: 4pt,
+    ///   shadow: (dy: 3pt, blur: 6pt),
+    /// )[A card]
+    /// #box(
+    ///   fill: white,
+    ///   inset: 8pt,
+    ///   shadow: (dx: 0pt, dy: 0pt, color: blue.transparentize(50%)),
+    /// )[A glow]
+    /// ```
+    pub shadow: Option<Shadow>,
+
     /// Whether to clip the content inside the box.
     ///
     /// Clipping is useful when the box's content is larger than the box itself,
@@ -8,7 +8,7 @@ use typst_utils::{LazyHash, Numeric};
 
 use crate::foundations::Label;
 use crate::introspection::{Location, Tag};
-use crate::layout::{Abs, Axes, FixedAlignment, Point, Size, Transform};
+use crate::layout::{Abs, Axes, FixedAlignment, Point, Ratio, Size, Transform};
 use crate::model::Destination;
 use crate::text::TextItem;
 use crate::visualize::{Color, Curve, FixedStroke, Geometry, Image, Paint, Shape};
@@ -359,6 +359,24 @@ impl Frame {
         }
     }
 
+    /// Make the contents of a frame translucent.
+    ///
+    /// The contents are composited as a whole, so overlapping parts of the
+    /// frame don't shine through each other.
+    pub fn opacity(&mut self, opacity: Ratio) {
+        if !self.is_empty() && !opacity.is_one() {
+            self.group(|g| g.opacity = opacity);
+        }
+    }
+
+    /// Blur the contents of a frame with a Gaussian blur of the given standard
+    /// deviation.
+    pub fn blur(&mut self, radius: Abs) {
+        if !self.is_empty() && radius > Abs::zero() {
+            self.group(|g| g.blur = radius);
+        }
+    }
+
     /// Add a label to the frame.
     pub fn label(&mut self, label: Label) {
         self.group(|g| g.label = Some(label));
@@ -520,6 +538,11 @@ pub struct GroupItem {
     pub transform: Transform,
     /// A curve which should be used to clip the group.
     pub clip: Option<Curve>,
+    /// The opacity with which the group is composited onto its surroundings.
+    pub opacity: Ratio,
+    /// The standard deviation of the Gaussian blur applied to the group. Zero
+    /// if the group is not blurred.
+    pub blur: Abs,
     /// The group's label.
     pub label: Option<Label>,
     /// The group's logical parent. All elements in this group are logically
@@ -536,6 +559,8 @@ impl GroupItem {
             frame,
             transform: Transform::identity(),
             clip: None,
+            opacity: Ratio::one(),
+            blur: Abs::zero(),
             label: None,
             parent: None,
         }
@@ -220,6 +220,44 @@ pub struct GridElem {
     #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
     pub row_gutter: TrackSizings,
 
+    /// The minimum widths of `{auto}` columns.
+    ///
+    /// Either specify a single bound for all columns or an array of bounds
+    /// corresponding to each column. If there are more columns than bounds,
+    /// the last bound is repeated. A bound of `{auto}` leaves the column
+    /// unconstrained. Bounds have no effect on columns that are not sized
+    /// `{auto}`.
+    ///
+    /// An `{auto}` column will not become narrower than its minimum width,
+    /// even if this means that the grid overflows the available space.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (auto, auto),
+    ///   column-min: 2cm,
+    ///   gutter: 4pt,
+    ///   rect[A], rect[B],
+    /// )
+    /// ```
+    pub column_min: TrackBounds,
+
+    /// The maximum widths of `{auto}` columns.
+    ///
+    /// Accepts the same values as @grid.column-min[`column-min`]. Content in
+    /// an `{auto}` column with a maximum width is laid out as if the column
+    /// were only that wide, which causes long text to wrap instead of
+    /// widening the column.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (auto, auto),
+    ///   column-max: (3cm, auto),
+    ///   gutter: 4pt,
+    ///   lorem(8), [Fits],
+    /// )
+    /// ```
+    pub column_max: TrackBounds,
+
     /// How much to pad the cells' content.
     ///
     /// To specify a uniform inset for all cells, you can use a single length
@@ -462,6 +500,17 @@ cast! {
     values: Array => Self(values.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
 }
 
+/// Bounds on the widths of `{auto}` tracks.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TrackBounds(pub SmallVec<[Smart<Rel<Length>>; 4]>);
+
+cast! {
+    TrackBounds,
+    self => self.0.into_value(),
+    bound: Smart<Rel<Length>> => Self(smallvec![bound]),
+    values: Array => Self(values.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
+}
+
 /// Any child of a grid element.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum GridChild {
@@ -8,7 +8,7 @@ use typst_library::diag::{
     At, Hint, HintedStrResult, HintedString, SourceResult, Trace, Tracepoint, bail,
 };
 use typst_library::engine::Engine;
-use typst_library::foundations::{Content, Fold, Packed, Smart, StyleChain};
+use typst_library::foundations::{Content, Fold, Packed, Resolve, Smart, StyleChain};
 use typst_library::layout::{
     Abs, Alignment, Axes, Celled, GridCell, GridChild, GridElem, GridItem, Length,
     OuterHAlignment, OuterVAlignment, Rel, ResolvedCelled, Sides, Sizing,
@@ -35,6 +35,8 @@ pub fn grid_to_cellgrid(
     let rows = elem.rows.get_ref(styles);
     let column_gutter = elem.column_gutter.get_ref(styles);
     let row_gutter = elem.row_gutter.get_ref(styles);
+    let column_min = elem.column_min.get_ref(styles);
+    let column_max = elem.column_max.get_ref(styles);
     let fill = elem.fill.get_ref(styles);
     let stroke = elem.stroke.resolve(styles);
 
@@ -71,6 +73,7 @@ pub fn grid_to_cellgrid(
         styles,
         elem.span(),
     )
+    .map(|grid| grid.with_col_bounds(&column_min.0, &column_max.0))
     .trace(engine.world, tracepoint, elem.span())
 }
 
@@ -87,6 +90,8 @@ pub fn table_to_cellgrid(
     let rows = elem.rows.get_ref(styles);
     let column_gutter = elem.column_gutter.get_ref(styles);
     let row_gutter = elem.row_gutter.get_ref(styles);
+    let column_min = elem.column_min.get_ref(styles);
+    let column_max = elem.column_max.get_ref(styles);
     let fill = elem.fill.get_ref(styles);
     let stroke = elem.stroke.resolve(styles);
 
@@ -123,6 +128,7 @@ pub fn table_to_cellgrid(
         styles,
         elem.span(),
     )
+    .map(|grid| grid.with_col_bounds(&column_min.0, &column_max.0))
     .trace(engine.world, tracepoint, elem.span())
 }
 
@@ -676,6 +682,12 @@ pub struct CellGrid {
     pub footer: Option<Repeatable<Footer>>,
     /// Whether this grid has gutters.
     pub has_gutter: bool,
+    /// The minimum widths of auto columns, indexed like `cols`. Empty if
+    /// there are no such bounds.
+    pub col_min: Vec<Smart<Rel<Length>>>,
+    /// The maximum widths of auto columns, indexed like `cols`. Empty if
+    /// there are no such bounds.
+    pub col_max: Vec<Smart<Rel<Length>>>,
 }
 
 impl CellGrid {
@@ -752,9 +764,50 @@ impl CellGrid {
             headers,
             footer,
             has_gutter,
+            col_min: vec![],
+            col_max: vec![],
         }
     }
 
+    /// Applies bounds on the widths of auto columns.
+    ///
+    /// The bounds are given per content column. If there are more columns
+    /// than bounds, the last bound is repeated. Gutter columns are never
+    /// bounded.
+    pub fn with_col_bounds(
+        mut self,
+        min: &[Smart<Rel<Length>>],
+        max: &[Smart<Rel<Length>>],
+    ) -> Self {
+        let expand = |bounds: &[Smart<Rel<Length>>]| {
+            let Some(&last) = bounds.last() else { return vec![] };
+            (0..self.cols.len())
+                .map(|x| {
+                    if self.has_gutter && x % 2 == 1 {
+                        return Smart::Auto;
+                    }
+                    let index = if self.has_gutter { x / 2 } else { x };
+                    bounds.get(index).copied().unwrap_or(last)
+                })
+                .collect()
+        };
+
+        let col_min = expand(min);
+        let col_max = expand(max);
+        self.col_min = col_min;
+        self.col_max = col_max;
+        self
+    }
+
+    /// The resolved bounds for the width of the auto column `x`.
+    pub fn col_bounds(&self, x: usize, styles: StyleChain, base: Abs) -> (Abs, Abs) {
+        let resolve = |bounds: &[Smart<Rel<Length>>], default| match bounds.get(x) {
+            Some(Smart::Custom(v)) => v.resolve(styles).relative_to(base),
+            _ => default,
+        };
+        (resolve(&self.col_min, Abs::zero()), resolve(&self.col_max, Abs::inf()))
+    }
+
     /// Get the grid entry in column `x` and row `y`.
     ///
     /// Returns `None` if it's a gutter cell.
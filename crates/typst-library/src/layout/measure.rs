@@ -1,13 +1,15 @@
 use comemo::Tracked;
 use typst_syntax::Span;
 
-use crate::diag::{At, SourceResult};
+use crate::diag::{At, SourceResult, warning};
 use crate::engine::Engine;
 use crate::foundations::{
-    Content, Context, Dict, Resolve, Smart, Target, TargetElem, dict, func,
+    Content, Context, Dict, Resolve, Smart, StyleChain, Target, TargetElem, dict, func,
+};
+use crate::introspection::{Locator, LocatorLink, Tag};
+use crate::layout::{
+    Abs, Axes, Frame, FrameItem, Length, PlaceElem, PlacementScope, Region, Size,
 };
-use crate::introspection::{Locator, LocatorLink};
-use crate::layout::{Abs, Axes, Length, Region, Size};
 
 /// Measures the layouted size of content.
 ///
@@ -100,6 +102,28 @@ pub fn measure(
         styles.chain(&style),
         pod,
     )?;
+    warn_page_placed(engine, styles, &frame);
     let Size { x, y } = frame.size();
     Ok(dict! { "width" => x, "height" => y })
 }
+
+/// Warns about page-scoped placed elements in the measured content. They are
+/// only laid out by the page and thus not part of the measured size.
+fn warn_page_placed(engine: &mut Engine, styles: StyleChain, frame: &Frame) {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => warn_page_placed(engine, styles, &group.frame),
+            FrameItem::Tag(Tag::Start(elem, _)) => {
+                let Some(elem) = elem.to_packed::<PlaceElem>() else { continue };
+                if elem.scope.get(styles) == PlacementScope::Page {
+                    engine.sink.warn(warning!(
+                        elem.span(),
+                        "page-scoped placement was ignored";
+                        hint: "page-scoped content does not contribute to the measured size";
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
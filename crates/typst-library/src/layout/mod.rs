@@ -19,6 +19,7 @@ mod layout_;
 mod length;
 #[path = "measure.rs"]
 mod measure_;
+mod overlay;
 mod pad;
 mod page;
 mod place;
@@ -51,6 +52,7 @@ pub use self::hide::*;
 pub use self::layout_::*;
 pub use self::length::*;
 pub use self::measure_::*;
+pub use self::overlay::*;
 pub use self::pad::*;
 pub use self::page::*;
 pub use self::place::*;
@@ -85,6 +87,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<BoxElem>();
     global.define_elem::<BlockElem>();
     global.define_elem::<StackElem>();
+    global.define_elem::<OverlayElem>();
     global.define_elem::<GridElem>();
     global.define_elem::<ColumnsElem>();
     global.define_elem::<ColbreakElem>();
@@ -96,6 +99,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<ScaleElem>();
     global.define_elem::<RotateElem>();
     global.define_elem::<SkewElem>();
+    global.define_elem::<TransformElem>();
     global.define_elem::<HideElem>();
     global.define_func::<measure>();
     global.define_func::<layout>();
@@ -0,0 +1,35 @@
+use crate::foundations::{Content, elem};
+
+/// Layers content on top of each other.
+///
+/// All children are laid out in the same area and drawn in order, so that
+/// later children appear on top of earlier ones. The overlay is as large as
+/// its largest child. Each child is positioned within that area according to
+/// its @align[alignment], which defaults to the top-left corner.
+///
+/// Compared to @place, the children of an overlay all contribute to its size,
+/// so that nothing sticks out of the overlay or covers surrounding content.
+///
+/// = Example <example>
+/// ```example
+/// #overlay(
+///   image("tiger.jpg", width: 80pt),
+///   align(top + right, circle(
+///     radius: 8pt,
+///     fill: red,
+///     align(center + horizon, text(white)[3]),
+///   )),
+///   align(bottom + center, text(white)[*Tiger*]),
+/// )
+/// ```
+///
+/// = Accessibility <accessibility>
+/// Overlays do not carry any special semantics. The contents of the overlay
+/// are read by Assistive Technology (AT) in the order in which they have been
+/// passed to this function.
+#[elem]
+pub struct OverlayElem {
+    /// The children to layer on top of each other.
+    #[variadic]
+    pub children: Vec<Content>,
+}
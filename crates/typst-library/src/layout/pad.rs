@@ -14,6 +14,19 @@ use crate::layout::{Length, Rel};
 /// _Typing speeds can be
 ///  measured in words per minute._
 /// ```
+///
+/// = Negative padding <negative>
+/// Padding may also be negative. In that case, the content is laid out in a
+/// larger area than is available and extends beyond the bounds of the padding
+/// element. This is useful to let something like an image bleed into the
+/// inset of a surrounding container.
+///
+/// ```example
+/// #block(fill: luma(235), inset: 10pt)[
+///   #pad(x: -10pt, image("tiger.jpg"))
+///   A tiger that spans the whole block.
+/// ]
+/// ```
 #[elem(title = "Padding")]
 pub struct PadElem {
     /// The padding at the left side.
@@ -218,6 +218,30 @@ pub struct PageElem {
     #[ghost]
     pub bleed: Margin<Rel<Length>>,
 
+    /// Whether to add printer's marks outside of the bleed.
+    ///
+    /// When enabled, crop marks indicating the trimmed size of the page are
+    /// drawn at its corners, and registration marks are drawn at the middle of
+    /// each edge. The marks are placed in an additional area outside of the
+    /// @page.bleed[bleed], so they are cut off during trimming. They are drawn
+    /// in the registration color, which prints on all color separations.
+    ///
+    /// In PDF export, the page's `MediaBox` is enlarged to hold the marks, and
+    /// a `BleedBox` and a `TrimBox` are defined for the page.
+    ///
+    /// ```example
+    /// #set page(
+    ///   width: 8cm,
+    ///   height: 5cm,
+    ///   bleed: 3mm,
+    ///   // The marks are not visible in the preview;
+    ///   // they exceed beyond the page.
+    ///   crop-marks: true,
+    /// )
+    /// ```
+    #[ghost]
+    pub crop_marks: bool,
+
     /// On which side the pages will be bound.
     ///
     /// - `{auto}`: Equivalent to `left` if the @text.dir[text direction] is
@@ -1,4 +1,8 @@
-use crate::foundations::{Cast, Content, Smart, elem, scope};
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    Cast, Content, Packed, Smart, StyleChain, Styles, Synthesize, elem, scope,
+};
 use crate::introspection::{Locatable, Tagged, Unqueriable};
 use crate::layout::{Alignment, Em, Length, Rel};
 
@@ -72,7 +76,7 @@ use crate::layout::{Alignment, Em, Length, Rel};
 /// where it logically appears in the document, regardless of where this
 /// function physically moved it. Put its markup where it would make the most
 /// sense in the reading order.
-#[elem(scope, Unqueriable, Locatable, Tagged)]
+#[elem(scope, Unqueriable, Locatable, Tagged, Synthesize)]
 pub struct PlaceElem {
     /// Relative to which position in the parent container to place the content.
     ///
@@ -110,6 +114,30 @@ pub struct PlaceElem {
     ///
     /// #lorem(25)
     /// ```
+    ///
+    /// The page scope positions the content relative to the full page,
+    /// including its margins, no matter how deeply the `place` call is nested
+    /// in other containers. The alignment and displacement are then relative
+    /// to the page's edges, and a missing vertical alignment means `{top}`.
+    /// Page-scoped content cannot float or wrap. It is laid out with the
+    /// page's styles, so set rules in the scope of the `place` call may not
+    /// apply to it. Page-scoped placement is only supported in the body of a
+    /// page. Elsewhere, for instance in a header or footer, it is ignored with
+    /// a warning.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #block(inset: 8pt, stroke: gray)[
+    ///   #place(
+    ///     top + right,
+    ///     scope: "page",
+    ///     dx: -8pt,
+    ///     dy: 8pt,
+    ///     rect(fill: red.lighten(60%))[Draft],
+    ///   )
+    ///   #lorem(12)
+    /// ]
+    /// ```
     pub scope: PlacementScope,
 
     /// Whether the placed element has floating layout.
@@ -137,10 +165,35 @@ pub struct PlaceElem {
     /// ```
     pub float: bool,
 
+    /// Whether the text of following paragraphs wraps around the placed
+    /// content.
+    ///
+    /// When enabled, the content is placed at the current position in the
+    /// flow and the lines of the paragraphs that follow it are shortened for
+    /// as long as they are beside the content. This requires the content to
+    /// be aligned to the start or end of the container without a vertical
+    /// alignment, and is not available for floating placement.
+    ///
+    /// Lines only flow around the content within the same container. A block
+    /// that follows the placed content ends the wrapping.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #place(
+    ///   left,
+    ///   wrap: true,
+    ///   clearance: 6pt,
+    ///   rect(width: 40pt, height: 50pt, fill: aqua),
+    /// )
+    ///
+    /// #lorem(40)
+    /// ```
+    pub wrap: bool,
+
     /// The spacing between the placed element and other elements in a floating
-    /// layout.
+    /// layout or, if `wrap` is enabled, the text flowing around it.
     ///
-    /// Has no effect if `float` is `{false}`.
+    /// Has no effect if neither `float` nor `wrap` is `{true}`.
     #[default(Em::new(1.5).into())]
     pub clearance: Length,
 
@@ -172,6 +225,11 @@ pub struct PlaceElem {
     /// The content to place.
     #[required]
     pub body: Content,
+
+    /// The styles in effect where page-scoped content was placed.
+    #[internal]
+    #[synthesized]
+    pub styles: Styles,
 }
 
 #[scope]
@@ -180,6 +238,17 @@ impl PlaceElem {
     type FlushElem;
 }
 
+impl Synthesize for Packed<PlaceElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        // Page-scoped content is laid out by the page run, where the styles
+        // at the element are not available anymore.
+        if self.scope.get(styles) == PlacementScope::Page {
+            self.styles = Some(styles.to_map());
+        }
+        Ok(())
+    }
+}
+
 /// Relative to which containing scope something shall be placed.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum PlacementScope {
@@ -188,6 +257,8 @@ pub enum PlacementScope {
     Column,
     /// Place relative to the parent, letting the content span over all columns.
     Parent,
+    /// Place relative to the page, regardless of the containers in between.
+    Page,
 }
 
 /// Asks the layout algorithm to place pending floating elements before
@@ -45,6 +45,37 @@ pub struct StackElem {
     /// Spacing to insert between items where no explicit spacing was provided.
     pub spacing: Option<Spacing>,
 
+    /// Whether to align the items of a horizontal stack on their baselines.
+    ///
+    /// By default, items are aligned along the cross axis according to their
+    /// @align[alignment]. When this is enabled, the items of a horizontal
+    /// stack are instead arranged such that their baselines line up. The
+    /// stack's own baseline is then at the same height, so that it sits on
+    /// the surrounding text's baseline when used inline. Items without a
+    /// baseline, like shapes, sit on it with their bottom edge.
+    ///
+    /// Has no effect on vertical stacks.
+    ///
+    /// ```example
+    /// #let badge(body) = box(
+    ///   fill: aqua,
+    ///   inset: (x: 3pt, y: 2pt),
+    ///   radius: 2pt,
+    ///   text(size: 0.7em, body),
+    /// )
+    ///
+    /// Status: #box(stack(
+    ///   dir: ltr,
+    ///   spacing: 3pt,
+    ///   baseline: true,
+    ///   circle(radius: 3pt, fill: green),
+    ///   [Done],
+    ///   badge[v2],
+    /// ))
+    /// ```
+    #[default(false)]
+    pub baseline: bool,
+
     /// The children to stack along the axis.
     #[variadic]
     pub children: Vec<StackChild>,
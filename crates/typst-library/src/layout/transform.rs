@@ -1,4 +1,9 @@
-use crate::foundations::{Content, Smart, cast, elem};
+use typst_utils::Numeric;
+
+use crate::diag::{HintedStrResult, bail};
+use crate::foundations::{
+    Array, Content, Resolve, Smart, StyleChain, Value, array, cast, elem,
+};
 use crate::layout::{Abs, Alignment, Angle, HAlignment, Length, Ratio, Rel, VAlignment};
 
 /// Moves content without affecting layout.
@@ -239,6 +244,148 @@ pub struct SkewElem {
     pub body: Content,
 }
 
+/// Applies an arbitrary affine transformation to content.
+///
+/// The transformation is given as a matrix `(a, b, c, d, e, f)`, in the same
+/// order as in PDF and SVG. It maps each point `(x, y)` of the content to
+/// `(a x + c y + e, b x + d y + f)`. This makes it possible to express effects
+/// that cannot be achieved by combining @move, @scale, @rotate, and @skew.
+///
+/// The layout will act as if the element was not transformed unless you
+/// specify `{reflow: true}`.
+///
+/// = Example <example>
+/// ```example
+/// #transform(matrix: (1, 0.2, -0.4, 1, 0pt, 0pt))[
+///   Sheared and slanted
+/// ]
+/// ```
+#[elem]
+pub struct TransformElem {
+    /// The transformation matrix.
+    ///
+    /// An array of six entries `(a, b, c, d, e, f)`. The first four are numbers
+    /// that scale, skew, and rotate the content, while `e` and `f` are lengths
+    /// by which it is translated horizontally and vertically.
+    ///
+    /// ```example
+    /// // Mirror horizontally, then move to the right.
+    /// #transform(matrix: (-1, 0, 0, 1, 10pt, 0pt))[Mirrored]
+    /// ```
+    #[default(TransformMatrix::IDENTITY)]
+    pub matrix: TransformMatrix,
+
+    /// The origin of the transformation.
+    ///
+    /// The origin will stay fixed during the operation.
+    ///
+    /// ```example
+    /// #let m = (0.8, 0.3, -0.3, 0.8, 0pt, 0pt)
+    /// X #box(transform(matrix: m, origin: bottom + left)[X]) X \
+    /// X #box(transform(matrix: m, origin: top + right)[X]) X
+    /// ```
+    #[fold]
+    #[default(HAlignment::Center + VAlignment::Horizon)]
+    pub origin: Alignment,
+
+    /// Whether the transformation impacts the layout.
+    ///
+    /// If set to `{false}`, the transformed content will retain the bounding
+    /// box of the original content. If set to `{true}`, the bounding box will
+    /// take the transformation of the content into account and adjust the
+    /// layout accordingly.
+    ///
+    /// ```example
+    /// Hello #transform(
+    ///   matrix: (1, 0.5, 0, 1, 0pt, 0pt),
+    ///   reflow: true,
+    /// )[World]!
+    /// ```
+    #[default(false)]
+    pub reflow: bool,
+
+    /// The content to transform.
+    #[required]
+    pub body: Content,
+}
+
+/// An affine transformation matrix whose translation is given as lengths.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TransformMatrix {
+    pub sx: Ratio,
+    pub ky: Ratio,
+    pub kx: Ratio,
+    pub sy: Ratio,
+    pub tx: Length,
+    pub ty: Length,
+}
+
+impl TransformMatrix {
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self {
+        sx: Ratio::one(),
+        ky: Ratio::zero(),
+        kx: Ratio::zero(),
+        sy: Ratio::one(),
+        tx: Length::zero(),
+        ty: Length::zero(),
+    };
+}
+
+impl Resolve for TransformMatrix {
+    type Output = Transform;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        Transform {
+            sx: self.sx,
+            ky: self.ky,
+            kx: self.kx,
+            sy: self.sy,
+            tx: self.tx.resolve(styles),
+            ty: self.ty.resolve(styles),
+        }
+    }
+}
+
+cast! {
+    TransformMatrix,
+    self => array![
+        self.sx.get(),
+        self.ky.get(),
+        self.kx.get(),
+        self.sy.get(),
+        self.tx,
+        self.ty,
+    ].into_value(),
+    v: Array => match v.as_slice() {
+        [a, b, c, d, e, f] => {
+            let factor = |v: &Value| -> HintedStrResult<Ratio> {
+                let v: f64 = v.clone().cast()?;
+                if !v.is_finite() {
+                    bail!("matrix entries must be finite");
+                }
+                Ok(Ratio::new(v))
+            };
+            let offset = |v: &Value| -> HintedStrResult<Length> {
+                let v: Length = v.clone().cast()?;
+                if !v.is_finite() {
+                    bail!("matrix entries must be finite");
+                }
+                Ok(v)
+            };
+            Self {
+                sx: factor(a)?,
+                ky: factor(b)?,
+                kx: factor(c)?,
+                sy: factor(d)?,
+                tx: offset(e)?,
+                ty: offset(f)?,
+            }
+        }
+        _ => bail!("expected 6 matrix entries, found {}", v.len())
+    },
+}
+
 /// A scale-skew-translate transformation.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Transform {
@@ -33,6 +33,7 @@ use typst_syntax::{DiagSpan, DiagSpanKind, FileId, Source};
 use typst_utils::{LazyHash, SmallBitSet};
 
 use crate::diag::FileResult;
+use crate::engine::DepthLimits;
 use crate::foundations::{
     Array, Binding, Bytes, Datetime, Dict, Duration, Module, NativeRuleMap, Scope, Styles,
 };
@@ -180,6 +181,8 @@ pub struct Library {
     pub std: Binding,
     /// In-development features that were enabled.
     pub features: Features,
+    /// Nesting limits for function calls and show rules.
+    pub depth_limits: DepthLimits,
 }
 
 /// Configurable builder for the standard library.
@@ -190,6 +193,7 @@ pub struct LibraryBuilder {
     routines: &'static Routines,
     inputs: Option<Dict>,
     features: Features,
+    depth_limits: DepthLimits,
 }
 
 impl LibraryBuilder {
@@ -200,6 +204,7 @@ impl LibraryBuilder {
             routines,
             inputs: None,
             features: Features::default(),
+            depth_limits: DepthLimits::default(),
         }
     }
 
@@ -217,6 +222,14 @@ impl LibraryBuilder {
         self
     }
 
+    /// Configure the nesting limits for function calls and show rules.
+    ///
+    /// See [`DepthLimits`] for why very high limits can crash the process.
+    pub fn with_depth_limits(mut self, depth_limits: DepthLimits) -> Self {
+        self.depth_limits = depth_limits;
+        self
+    }
+
     /// Consumes the builder and returns a `Library`.
     pub fn build(self) -> Library {
         let math = math::module();
@@ -230,6 +243,7 @@ impl LibraryBuilder {
             rules: (self.routines.rules)(),
             std: Binding::detached(global),
             features: self.features,
+            depth_limits: self.depth_limits,
         }
     }
 }
@@ -0,0 +1,210 @@
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use typst_utils::NonZeroExt;
+
+use crate::diag::{SourceResult, bail, warning};
+use crate::engine::Engine;
+use crate::foundations::{
+    Content, NativeElement, Packed, Selector, StyleChain, elem, scope,
+};
+use crate::introspection::{
+    Count, Counter, CounterUpdate, Locatable, QueryIntrospection,
+};
+use crate::model::{DirectLinkElem, Numbering, NumberingPattern};
+use crate::text::SuperElem;
+
+/// An endnote.
+///
+/// Like a @footnote, an endnote inserts a superscript number into the text.
+/// However, the note itself is not displayed at the bottom of the page.
+/// Instead, notes are collected and displayed wherever you place an
+/// @endnotes[`endnotes`] listing, for example at the end of each chapter or in
+/// the back matter of your document. The number in the text links to the note
+/// and the note links back to its number.
+///
+/// = Example <example>
+/// ```example
+/// Typst was first released
+/// in 2023.#endnote[As a public beta.]
+/// It is written in Rust.#endnote[
+///   A systems programming language.
+/// ]
+///
+/// #endnotes()
+/// ```
+///
+/// Notes are numbered sequentially throughout your document. To restart the
+/// numbering for each chapter, you can reset the endnote @counter[counter],
+/// e.g. in a heading show rule.
+///
+/// To customize the appearance of the entries in the listing, see
+/// @endnote.entry.
+#[elem(scope, Locatable, Count)]
+pub struct EndnoteElem {
+    /// How to number endnotes. Accepts a
+    /// @numbering[numbering pattern or function] taking a single number.
+    ///
+    /// ```example
+    /// #set endnote(numbering: "i")
+    ///
+    /// Roman#endnote[One] numbers#endnote[Two]
+    ///
+    /// #endnotes()
+    /// ```
+    #[default(Numbering::Pattern(NumberingPattern::from_str("1").unwrap()))]
+    pub numbering: Numbering,
+
+    /// The content of the endnote.
+    #[required]
+    pub body: Content,
+}
+
+#[scope]
+impl EndnoteElem {
+    #[elem]
+    type EndnoteEntry;
+}
+
+impl Packed<EndnoteElem> {
+    /// Returns the content that holds the number and links to the endnote
+    /// entry. If no listing follows the note, the number is not linked.
+    pub fn realize(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+    ) -> SourceResult<Content> {
+        let span = self.span();
+        let loc = self.location().unwrap();
+        let numbering = self.numbering.get_ref(styles);
+        let counter = Counter::of(EndnoteElem::ELEM);
+        let num = counter.display_at(engine, loc, styles, numbering, span)?;
+
+        // The entry only exists if a listing follows the note.
+        let listings = engine.introspect(QueryIntrospection(
+            Selector::After {
+                selector: Arc::new(EndnotesElem::ELEM.select()),
+                start: Arc::new(loc.into()),
+                inclusive: false,
+            },
+            span,
+        ));
+        if listings.is_empty() {
+            engine.sink.warn(warning!(
+                span, "endnote is not displayed in any listing";
+                hint: "add an `endnotes()` listing after the note";
+            ));
+            return Ok(num);
+        }
+
+        let alt = num.plain_text();
+        Ok(DirectLinkElem::new(loc.variant(1), num, Some(alt))
+            .pack()
+            .spanned(span))
+    }
+}
+
+impl Count for Packed<EndnoteElem> {
+    fn update(&self) -> Option<CounterUpdate> {
+        Some(CounterUpdate::Step(NonZeroUsize::ONE))
+    }
+}
+
+/// A listing of endnotes.
+///
+/// Displays all @endnote[endnotes] that occur before it in the document, but
+/// after the previous listing. To display the notes of each chapter at its
+/// end, you can thus place a listing at the end of each chapter. Endnotes
+/// after the last listing are not displayed and produce a warning.
+///
+/// ```example
+/// = Introduction
+/// Some text.#endnote[A note.]
+///
+/// #endnotes()
+///
+/// = Conclusion
+/// More text.#endnote[Another note.]
+///
+/// #endnotes()
+/// ```
+#[elem(Locatable)]
+pub struct EndnotesElem {}
+
+impl Packed<EndnotesElem> {
+    /// Finds the endnotes displayed by this listing.
+    pub fn notes(&self, engine: &mut Engine) -> Vec<Packed<EndnoteElem>> {
+        let span = self.span();
+        let loc = self.location().unwrap();
+        let before = |selector: Selector| Selector::Before {
+            selector: Arc::new(selector),
+            end: Arc::new(loc.into()),
+            inclusive: false,
+        };
+
+        // Only collect the notes after the previous listing.
+        let mut selector = before(EndnoteElem::ELEM.select());
+        let previous = engine
+            .introspect(QueryIntrospection(before(EndnotesElem::ELEM.select()), span));
+        if let Some(prev) = previous.last().and_then(Content::location) {
+            selector = Selector::After {
+                selector: Arc::new(selector),
+                start: Arc::new(prev.into()),
+                inclusive: false,
+            };
+        }
+
+        engine
+            .introspect(QueryIntrospection(selector, span))
+            .into_iter()
+            .map(|note| note.into_packed::<EndnoteElem>().unwrap())
+            .collect()
+    }
+}
+
+/// An entry in an endnote listing.
+///
+/// This function is not intended to be called directly. Instead, it is used in
+/// set and show rules to customize endnote listings.
+///
+/// ```example
+/// #show endnote.entry: set text(blue)
+///
+/// Some text.#endnote[A blue note.]
+///
+/// #endnotes()
+/// ```
+#[elem(name = "entry", title = "Endnote Entry", Locatable)]
+pub struct EndnoteEntry {
+    /// The endnote for this entry. Its location can be used to determine the
+    /// endnote counter state.
+    #[required]
+    pub note: Packed<EndnoteElem>,
+}
+
+impl Packed<EndnoteEntry> {
+    /// Returns the content of the superscript that holds the number and links
+    /// back to the endnote, and the entry body.
+    pub fn realize(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+    ) -> SourceResult<(Content, Content)> {
+        let span = self.span();
+        let numbering = self.note.numbering.get_ref(StyleChain::default());
+        let counter = Counter::of(EndnoteElem::ELEM);
+        let Some(dest) = self.note.location() else {
+            bail!(
+                span, "endnote entry must have a location";
+                hint: "try using a query or a show rule to customize the endnote instead";
+            );
+        };
+
+        let num = counter.display_at(engine, dest, styles, numbering, span)?;
+        let alt = num.plain_text();
+        let link = DirectLinkElem::new(dest, num, Some(alt)).pack().spanned(span);
+        let sup = SuperElem::new(link).pack().spanned(span);
+        Ok((sup, self.note.body.clone()))
+    }
+}
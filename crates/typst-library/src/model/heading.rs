@@ -204,6 +204,23 @@ pub struct HeadingElem {
     #[default(Smart::Auto)]
     pub bookmarked: Smart<bool>,
 
+    /// A short version of the heading's title. If set, it is used instead of
+    /// the full title in the @outline[outline] and in the exported PDF's
+    /// bookmarks.
+    ///
+    /// The short title is also available to show rules and queries, so you can
+    /// use it in running headers as well.
+    ///
+    /// ```example
+    /// #outline()
+    ///
+    /// #heading(short: [Results])[
+    ///   Experimental results for the
+    ///   first and second series
+    /// ]
+    /// ```
+    pub short: Option<Content>,
+
     /// The indent all but the first line of a heading should have.
     ///
     /// The default value of `{auto}` uses the width of the numbering as indent
@@ -339,7 +356,9 @@ impl Outlinable for Packed<HeadingElem> {
     }
 
     fn body(&self) -> Content {
-        self.body.clone()
+        self.short
+            .get_cloned(StyleChain::default())
+            .unwrap_or_else(|| self.body.clone())
     }
 }
 
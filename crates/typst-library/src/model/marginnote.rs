@@ -0,0 +1,80 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{Content, Packed, StyleChain, Styles, Synthesize, elem};
+use crate::introspection::Locatable;
+use crate::layout::{Em, Length, OuterHAlignment};
+
+/// A note in the page margin.
+///
+/// Places its content next to the line in which it is called, in the margin
+/// of the page. Margin notes are commonly used for side remarks, glosses, and
+/// short annotations that should stay close to the text they refer to.
+///
+/// If notes would overlap, a note is pushed downwards until it clears the
+/// preceding note on the same side of the page.
+///
+/// = Example <example>
+/// ```example
+/// #set page(margin: (right: 4cm))
+///
+/// Typst is a markup-based
+/// typesetting system.
+/// #marginnote[Released in 2023.]
+/// It is designed to be as
+/// powerful as LaTeX while
+/// being much easier to learn.
+/// #marginnote[Easier to learn, at least.]
+/// #marginnote[And faster to compile.]
+/// ```
+///
+/// Note that margin notes are only supported in the main flow of a page. The
+/// page margin must be large enough to fit the note as it does not affect the
+/// page's layout in any way.
+#[elem(name = "marginnote", title = "Margin Note", Locatable, Synthesize)]
+pub struct MarginNoteElem {
+    /// The margin in which to place the note.
+    ///
+    /// In multi-column layouts, this is ignored. Notes in the last column are
+    /// placed in the end margin and notes in all other columns in the start
+    /// margin, just like line numbers.
+    ///
+    /// ```example
+    /// #set page(margin: (x: 3cm))
+    ///
+    /// Notes can go left
+    /// #marginnote(side: left)[Left]
+    /// or right.
+    /// #marginnote(side: right)[Right]
+    /// ```
+    #[default(OuterHAlignment::End)]
+    pub side: OuterHAlignment,
+
+    /// The width of the note.
+    #[default(Em::new(8.0).into())]
+    pub width: Length,
+
+    /// The horizontal distance between the text and the note.
+    #[default(Em::new(1.0).into())]
+    pub clearance: Length,
+
+    /// The minimum vertical distance between consecutive notes in the same
+    /// margin.
+    #[default(Em::new(0.5).into())]
+    pub gap: Length,
+
+    /// The content to put into the margin.
+    #[required]
+    pub body: Content,
+
+    /// The styles in effect where the note was called.
+    #[internal]
+    #[synthesized]
+    pub styles: Styles,
+}
+
+impl Synthesize for Packed<MarginNoteElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        self.styles = Some(styles.to_map());
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ mod cite;
 mod divider;
 mod document;
 mod emph;
+mod endnote;
 #[path = "enum.rs"]
 mod enum_;
 mod figure;
@@ -13,6 +14,7 @@ mod footnote;
 mod heading;
 mod link;
 mod list;
+mod marginnote;
 #[path = "numbering.rs"]
 mod numbering_;
 mod outline;
@@ -30,12 +32,14 @@ pub use self::cite::*;
 pub use self::divider::*;
 pub use self::document::*;
 pub use self::emph::*;
+pub use self::endnote::*;
 pub use self::enum_::*;
 pub use self::figure::*;
 pub use self::footnote::*;
 pub use self::heading::*;
 pub use self::link::*;
 pub use self::list::*;
+pub use self::marginnote::*;
 pub use self::numbering_::*;
 pub use self::outline::*;
 pub use self::par::*;
@@ -70,6 +74,9 @@ pub fn define(global: &mut Scope, features: &Features) {
     global.define_elem::<FigureElem>();
     global.define_elem::<QuoteElem>();
     global.define_elem::<FootnoteElem>();
+    global.define_elem::<EndnoteElem>();
+    global.define_elem::<EndnotesElem>();
+    global.define_elem::<MarginNoteElem>();
     global.define_elem::<OutlineElem>();
     global.define_elem::<RefElem>();
     global.define_elem::<CiteElem>();
@@ -224,6 +224,34 @@ pub struct ParElem {
     #[default(Em::new(1.2).into())]
     pub spacing: Length,
 
+    /// A vertical grid to which the baselines of paragraph lines snap.
+    ///
+    /// When set, each line in the main flow of a page is moved down just far
+    /// enough for its baseline to fall on a multiple of the given length,
+    /// measured from the top of the column. This keeps lines on facing pages
+    /// and in neighbouring columns aligned, which is often desired in book
+    /// design. Blocks in the main flow, like headings or figures, are snapped
+    /// with their first baseline, or with their top edge if they have none.
+    /// Lines within such blocks are not snapped individually, but the
+    /// following lines return to the grid.
+    ///
+    /// For the best results, choose a grid that matches the distance between
+    /// two baselines of the body text, i.e. the text's line height plus the
+    /// leading.
+    ///
+    /// ```example
+    /// #set page(columns: 2)
+    /// #set par(baseline-grid: 14pt, leading: 0.65em)
+    ///
+    /// #lorem(12)
+    ///
+    /// = Heading
+    /// #lorem(14)
+    /// #colbreak()
+    /// #lorem(30)
+    /// ```
+    pub baseline_grid: Option<Length>,
+
     /// Whether to justify text in its line.
     ///
     /// Hyphenation will be enabled for justified paragraphs if the
@@ -429,6 +457,34 @@ pub struct ParElem {
     /// ```
     pub hanging_indent: Length,
 
+    /// The positions to which @tab[tabs] in the paragraph advance.
+    ///
+    /// Each tab stop can be given as a length, which is the distance of a
+    /// start-aligned stop from the start of the line, or as a dictionary with
+    /// the following keys:
+    /// - `pos`: The distance of the stop from the start of the line.
+    /// - `align`: How the content following a tab is aligned at the stop. This
+    ///   can be a horizontal @alignment or `{"decimal"}`, which aligns the first
+    ///   decimal point in the content with the stop. Defaults to `{start}`.
+    /// - `leader`: Content that is repeated to fill the gap before the stop,
+    ///   for example dots in a table of contents. Defaults to `{none}`.
+    ///
+    /// In right-to-left text, the stops are measured from the right. Lines that
+    /// are shortened by @place.wrap[wrapped] content keep the stops of the
+    /// paragraph.
+    ///
+    /// ```example
+    /// #set par(tab-stops: (
+    ///   2cm,
+    ///   (pos: 4.5cm, align: "decimal"),
+    /// ))
+    ///
+    /// Apples #tab() 12 #tab() 3.50 \
+    /// Pears #tab() 7 #tab() 12.75 \
+    /// Plums #tab() 144 #tab() 0.5
+    /// ```
+    pub tab_stops: Vec<TabStop>,
+
     /// The contents of the paragraph.
     #[required]
     pub body: Content,
@@ -707,6 +763,61 @@ impl Fold for FirstLineIndent {
     }
 }
 
+/// A position in a paragraph's lines to which tabs advance.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct TabStop {
+    /// The distance of the stop from the start of the line.
+    pub pos: Length,
+    /// How the content following the tab is aligned at the stop.
+    pub align: TabAlignment,
+    /// Content that is repeated to fill the gap before the stop.
+    pub leader: Option<Content>,
+}
+
+cast! {
+    TabStop,
+    self => {
+        let mut dict = Dict::new();
+        dict.insert("pos".into(), self.pos.into_value());
+        dict.insert("align".into(), self.align.into_value());
+        dict.insert("leader".into(), self.leader.into_value());
+        Value::Dict(dict)
+    },
+    pos: Length => Self { pos, align: TabAlignment::default(), leader: None },
+    mut dict: Dict => {
+        let pos = dict.take("pos")?.cast()?;
+        let align = dict.take("align").ok().map(Value::cast).transpose()?;
+        let leader = dict.take("leader").ok().map(Value::cast).transpose()?;
+        dict.finish(&["pos", "align", "leader"])?;
+        Self { pos, align: align.unwrap_or_default(), leader: leader.flatten() }
+    },
+}
+
+/// How the content following a tab is aligned at a tab stop.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TabAlignment {
+    /// Align the content horizontally at the stop.
+    Align(HAlignment),
+    /// Align the first decimal point in the content at the stop.
+    Decimal,
+}
+
+impl Default for TabAlignment {
+    fn default() -> Self {
+        Self::Align(HAlignment::Start)
+    }
+}
+
+cast! {
+    TabAlignment,
+    self => match self {
+        Self::Align(align) => align.into_value(),
+        Self::Decimal => "decimal".into_value(),
+    },
+    align: HAlignment => Self::Align(align),
+    "decimal" => Self::Decimal,
+}
+
 /// A paragraph break.
 ///
 /// This starts a new paragraph. Especially useful when used within code like
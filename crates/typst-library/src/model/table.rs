@@ -13,7 +13,7 @@ use crate::introspection::{Locatable, Tagged};
 use crate::layout::resolve::{CellGrid, table_to_cellgrid};
 use crate::layout::{
     Abs, Alignment, Celled, GridCell, GridFooter, GridHLine, GridHeader, GridVLine,
-    Length, OuterHAlignment, OuterVAlignment, Rel, Sides, TrackSizings,
+    Length, OuterHAlignment, OuterVAlignment, Rel, Sides, TrackBounds, TrackSizings,
 };
 use crate::model::Figurable;
 use crate::pdf::TableCellKind;
@@ -163,6 +163,25 @@ pub struct TableElem {
     #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
     pub row_gutter: TrackSizings,
 
+    /// The minimum widths of `{auto}` columns. See the
+    /// @grid.column-min[grid documentation] for more information on column
+    /// bounds.
+    pub column_min: TrackBounds,
+
+    /// The maximum widths of `{auto}` columns. See the
+    /// @grid.column-max[grid documentation] for more information on column
+    /// bounds.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   column-max: (2.5cm, auto),
+    ///   [*Name*], [*Notes*],
+    ///   [Supercalifragilistic], [Wraps],
+    /// )
+    /// ```
+    pub column_max: TrackBounds,
+
     /// How much to pad the cells' content.
     ///
     /// To specify the same inset for all cells, use a single length for all
@@ -46,6 +46,8 @@ struct FontInner {
     index: u32,
     /// Metadata about the font.
     info: FontInfo,
+    /// Whether the font provides small capitals through the `smcp` feature.
+    smallcaps: bool,
     // NOTE: `ttf` references `data`, so it's important for `data` to be
     // dropped after `ttf` or `ttf` will be left dangling while the data is
     // dropped. Fields are dropped in declaration order, so `data` needs to be
@@ -72,8 +74,11 @@ impl Font {
 
         let ttf = ttf_parser::Face::parse(slice, index).ok()?;
         let info = FontInfo::from_ttf(&ttf)?;
+        let smallcaps = ttf.tables().gsub.is_some_and(|gsub| {
+            gsub.features.find(ttf_parser::Tag::from_bytes(b"smcp")).is_some()
+        });
 
-        Some(Self(Arc::new(FontInner { index, info, ttf, data })))
+        Some(Self(Arc::new(FontInner { index, info, smallcaps, ttf, data })))
     }
 
     /// Parse all fonts in the given data.
@@ -97,6 +102,12 @@ impl Font {
         &self.0.info
     }
 
+    /// Whether the font provides small capitals through the `smcp` OpenType
+    /// feature.
+    pub fn has_smallcaps(&self) -> bool {
+        self.0.smallcaps
+    }
+
     /// Determine the font's PostScript name.
     pub fn post_script_name(&self) -> Option<String> {
         find_name(&self.0.ttf, name_id::POST_SCRIPT_NAME)
@@ -0,0 +1,46 @@
+use crate::foundations::{Content, elem};
+use crate::text::TextDir;
+
+/// Isolates content from the surrounding text's bidirectional ordering.
+///
+/// When mixing right-to-left and left-to-right text, the Unicode
+/// bidirectional algorithm determines the order in which runs of text are
+/// displayed. Neutral characters like numbers, spaces, and punctuation take
+/// their direction from the surrounding text, which sometimes yields the
+/// wrong result, for example, when an embedded Latin name ends in
+/// punctuation within Hebrew text.
+///
+/// The `isolate` function lays out its content as an independent unit, so
+/// that it neither affects nor is affected by the ordering of the surrounding
+/// text. To override the direction of individual runs of text instead, use
+/// the @text.dir[`dir`] property of text.
+///
+/// = Example <example>
+/// ```example
+/// #set text(lang: "he")
+/// המשתמש #isolate[user123!] הגיב.
+///
+/// המשתמש user123! הגיב.
+/// ```
+///
+/// = HTML export <html>
+/// In HTML export, the content is wrapped in a `<bdi>` element.
+#[elem(title = "Bidirectional Isolate")]
+pub struct IsolateElem {
+    /// The direction of the isolated content.
+    ///
+    /// - `{auto}`: Determine the direction from the first strongly directional
+    ///   character within the content.
+    /// - `{ltr}`: Layout the content from left to right.
+    /// - `{rtl}`: Layout the content from right to left.
+    ///
+    /// ```example
+    /// #set text(lang: "ar")
+    /// #isolate(dir: ltr)[1 - 2]
+    /// ```
+    pub dir: TextDir,
+
+    /// The content to isolate.
+    #[required]
+    pub body: Content,
+}
@@ -3,30 +3,36 @@
 mod case;
 mod deco;
 mod font;
+mod isolate;
 mod item;
 mod lang;
 mod linebreak;
 #[path = "lorem.rs"]
 mod lorem_;
 mod raw;
+mod ruby;
 mod shift;
 #[path = "smallcaps.rs"]
 mod smallcaps_;
 mod smartquote;
 mod space;
+mod tab;
 
 pub use self::case::*;
 pub use self::deco::*;
 pub use self::font::*;
+pub use self::isolate::*;
 pub use self::item::*;
 pub use self::lang::*;
 pub use self::linebreak::*;
 pub use self::lorem_::*;
 pub use self::raw::*;
+pub use self::ruby::*;
 pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
 pub use self::space::*;
+pub use self::tab::*;
 
 use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
@@ -57,6 +63,7 @@ pub(super) fn define(global: &mut Scope) {
     global.start_category(crate::Category::Text);
     global.define_elem::<TextElem>();
     global.define_elem::<LinebreakElem>();
+    global.define_elem::<TabElem>();
     global.define_elem::<SmartQuoteElem>();
     global.define_elem::<SubElem>();
     global.define_elem::<SuperElem>();
@@ -65,6 +72,8 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<StrikeElem>();
     global.define_elem::<HighlightElem>();
     global.define_elem::<SmallcapsElem>();
+    global.define_elem::<RubyElem>();
+    global.define_elem::<IsolateElem>();
     global.define_elem::<RawElem>();
     global.define_func::<lower>();
     global.define_func::<upper>();
@@ -392,6 +401,31 @@ pub struct TextElem {
     #[ghost]
     pub overhang: bool,
 
+    /// Whether to justify Arabic text by elongating the connections between
+    /// letters.
+    ///
+    /// When enabled, justified Arabic text is stretched not only by widening
+    /// the spaces, but also by inserting _kashidas_ (tatweels) into words.
+    /// Each word is elongated at most at one position, its last connection
+    /// between two letters. This requires the font to have a tatweel glyph.
+    ///
+    /// ```example
+    /// #set page(width: 180pt)
+    /// #set text(lang: "ar", font: "Noto Sans Arabic")
+    /// #set par(justify: true)
+    ///
+    /// #let body = [
+    ///   هذا نص عربي لاختبار ضبط المسافات بين الكلمات في فقرة قصيرة.
+    /// ]
+    /// #body
+    ///
+    /// #set text(kashida: true)
+    /// #body
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub kashida: bool,
+
     /// The top end of the conceptual frame around the text used for layout and
     /// positioning. This affects the size of containers that hold text.
     ///
@@ -564,6 +598,32 @@ pub struct TextElem {
     #[ghost]
     pub hyphenate: Smart<bool>,
 
+    /// Words with custom hyphenation that take precedence over the language's
+    /// hyphenation patterns.
+    ///
+    /// Each entry is a word in which the permissible hyphenation points are
+    /// marked with hyphens. Words are matched case-insensitively. An entry
+    /// without any hyphens prevents the word from being hyphenated at all.
+    ///
+    /// This is useful for domain-specific vocabulary that the patterns
+    /// hyphenate incorrectly. Exceptions are also respected for languages
+    /// without built-in hyphenation patterns.
+    ///
+    /// ```example
+    /// #set page(width: 80pt)
+    /// #set text(hyphenate: true)
+    ///
+    /// Compile with typesetting.
+    ///
+    /// #set text(hyphenation-exceptions: (
+    ///   "type-set-ting",
+    ///   "compile",
+    /// ))
+    /// Compile with typesetting.
+    /// ```
+    #[ghost]
+    pub hyphenation_exceptions: Vec<Str>,
+
     /// The "cost" of various choices when laying out text. A higher cost means
     /// the layout engine will make the choice less often. Costs are specified
     /// as a ratio of the default cost, so `{50%}` will make text layout twice
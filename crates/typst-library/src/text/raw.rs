@@ -13,19 +13,19 @@ use unicode_segmentation::UnicodeSegmentation;
 use super::Lang;
 use crate::World;
 use crate::diag::{
-    LineCol, LoadError, LoadResult, LoadedWithin, ReportTextPos, SourceResult,
+    LineCol, LoadError, LoadResult, LoadedWithin, ReportTextPos, SourceResult, bail,
 };
 use crate::engine::Engine;
 use crate::foundations::{
-    Bytes, Content, Derived, OneOrMultiple, Packed, PlainText, ShowSet, Smart,
-    StyleChain, Styles, Synthesize, Target, TargetElem, cast, elem, scope,
+    Array, Bytes, Content, Derived, IntoValue, OneOrMultiple, Packed, PlainText, ShowSet,
+    Smart, StyleChain, Styles, Synthesize, Target, TargetElem, array, cast, elem, scope,
 };
 use crate::introspection::{Locatable, Tagged};
 use crate::layout::{Em, HAlignment};
 use crate::loading::{DataSource, Load};
-use crate::model::{Figurable, ParElem};
+use crate::model::{Figurable, Numbering, ParElem};
 use crate::routines::Routines;
-use crate::text::{FontFamily, FontList, LocalName, TextElem, TextSize};
+use crate::text::{FontFamily, FontList, HighlightElem, LocalName, TextElem, TextSize};
 use crate::visualize::Color;
 
 /// Raw text with optional syntax highlighting.
@@ -446,6 +446,60 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// How to number the lines of a raw block.
+    ///
+    /// The numbers are displayed in a column before the lines. They always
+    /// refer to the line's position in the full raw text, even if only a
+    /// @raw.range[range] of it is shown. This option is ignored if this is not
+    /// a raw block. In HTML export, it is ignored with a warning.
+    ///
+    /// ````example
+    /// #set raw(numbering: "1")
+    ///
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello World!");
+    /// }
+    /// ```
+    /// ````
+    pub numbering: Option<Numbering>,
+
+    /// The numbers of the lines that should be highlighted.
+    ///
+    /// The highlighted lines are wrapped in a @highlight element, so you can
+    /// customize their look with a show-set rule.
+    ///
+    /// ````example
+    /// #set raw(highlighted: (2, 3))
+    /// #show raw: set highlight(fill: aqua.lighten(60%))
+    ///
+    /// ```py
+    /// def fib(n):
+    ///     if n < 2:
+    ///         return n
+    ///     return fib(n - 1) + fib(n - 2)
+    /// ```
+    /// ````
+    pub highlighted: Vec<i64>,
+
+    /// The range of lines to display, given as an array of the first and the
+    /// last line number. Both ends are inclusive.
+    ///
+    /// The full text is still highlighted, so that syntax highlighting is
+    /// correct even if the displayed lines start in the middle of a construct.
+    ///
+    /// ````example
+    /// #set raw(range: (2, 3), numbering: "1")
+    ///
+    /// ```rust
+    /// fn main() {
+    ///     let x = 1;
+    ///     println!("{x}");
+    /// }
+    /// ```
+    /// ````
+    pub range: Option<RawRange>,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the @raw.line[`raw.line` element]. Allows more
@@ -487,7 +541,21 @@ impl Synthesize for Packed<RawElem> {
         engine: &mut Engine,
         styles: StyleChain,
     ) -> SourceResult<()> {
-        let seq = self.highlight(engine.library.routines, styles);
+        let mut seq = self.highlight(engine.library.routines, styles);
+
+        if let Some(range) = self.range.get(styles) {
+            seq.retain(|line| range.contains(line.number));
+        }
+
+        let highlighted = self.highlighted.get_ref(styles);
+        for line in &mut seq {
+            if highlighted.contains(&line.number) {
+                let body =
+                    HighlightElem::new(line.body.clone()).pack().spanned(line.span());
+                line.body = body;
+            }
+        }
+
         self.lines = Some(seq);
         Ok(())
     }
@@ -657,6 +725,38 @@ cast! {
     v: Content => v.unpack::<Self>().map_err(|_| "expected raw text")?
 }
 
+/// A range of lines in raw text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RawRange {
+    /// The number of the first line in the range.
+    pub start: i64,
+    /// The number of the last line in the range.
+    pub end: i64,
+}
+
+impl RawRange {
+    /// Whether the line with the given number is in the range.
+    pub fn contains(&self, number: i64) -> bool {
+        (self.start..=self.end).contains(&number)
+    }
+}
+
+cast! {
+    RawRange,
+    self => array![self.start, self.end].into_value(),
+    array: Array => {
+        let mut iter = array.into_iter();
+        let (start, end) = match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), Some(b), None) => (a.cast()?, b.cast()?),
+            _ => bail!("array must contain exactly two entries"),
+        };
+        if start < 1 || end < start {
+            bail!("line range must be ascending and start at 1 or later");
+        }
+        Self { start, end }
+    },
+}
+
 /// The content of the raw text.
 #[derive(Debug, Clone, Hash)]
 pub enum RawContent {
@@ -0,0 +1,70 @@
+use crate::foundations::{Content, elem};
+use crate::layout::{Em, Length, OuterVAlignment};
+
+/// Annotates text with small ruby text.
+///
+/// Ruby annotations are short runs of text placed alongside base text. They
+/// are most commonly used in East Asian typography to indicate the
+/// pronunciation of characters, as with Japanese _furigana_, but also serve
+/// for glosses and similar annotations.
+///
+/// The annotation is centered on the base text. If the annotation is wider
+/// than the base, it may @ruby.overhang[overhang] the neighbouring text
+/// slightly. A line that contains ruby grows to make room for the annotations.
+///
+/// = Example <example>
+/// ```example
+/// #set text(lang: "ja", font: "Noto Serif CJK SC")
+/// #ruby[東京][とうきょう]に
+/// #ruby[行][い]きます。
+/// ```
+///
+/// = HTML export <html>
+/// In HTML export, ruby is exported as a `<ruby>` element with an `<rt>`
+/// element for the annotation.
+#[elem(title = "Ruby")]
+pub struct RubyElem {
+    /// The size of the annotation's text.
+    ///
+    /// Relative lengths are relative to the size of the base text.
+    ///
+    /// ```example
+    /// #ruby(size: 0.7em)[rubus][bramble]
+    /// ```
+    #[default(Em::new(0.5).into())]
+    pub size: Length,
+
+    /// On which side of the base text to place the annotation.
+    ///
+    /// ```example
+    /// #ruby(position: bottom)[漢字][かんじ]
+    /// ```
+    #[default(OuterVAlignment::Top)]
+    pub position: OuterVAlignment,
+
+    /// The distance between the base text and the annotation.
+    #[default(Em::new(0.15).into())]
+    pub gap: Length,
+
+    /// Whether the annotation may extend into the neighbouring text if it is
+    /// wider than the base text.
+    ///
+    /// When enabled, the annotation may overhang by up to half of its text
+    /// size on each side. Otherwise, the base text is spaced out so that the
+    /// annotation fits.
+    ///
+    /// ```example
+    /// あ#ruby[字][あざな]い \
+    /// あ#ruby(overhang: false)[字][あざな]い
+    /// ```
+    #[default(true)]
+    pub overhang: bool,
+
+    /// The base text to annotate.
+    #[required]
+    pub body: Content,
+
+    /// The annotation to place alongside the base text.
+    #[required]
+    pub annotation: Content,
+}
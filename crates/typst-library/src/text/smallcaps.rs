@@ -19,8 +19,11 @@ use crate::foundations::{Content, elem};
 /// #show smallcaps: set text(font: "Latin Modern Roman Caps")
 /// ```
 ///
-/// In the future, this function will support synthesizing smallcaps from normal
-/// letters, but this is not yet implemented.
+/// If the font does not support the `smcp` feature, Typst synthesizes small
+/// capitals by shaping the affected letters as uppercase letters at a reduced
+/// size and emits a warning. Since synthesized small capitals are merely
+/// scaled-down capitals, they tend to look lighter than the surrounding text,
+/// so a font with true small capitals should be preferred where available.
 ///
 /// = Smallcaps headings <smallcaps-headings>
 /// You can use a @reference:styling:show-rules[show rule] to apply smallcaps
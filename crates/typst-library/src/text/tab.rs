@@ -0,0 +1,25 @@
+use crate::foundations::elem;
+
+/// Advances to the next tab stop.
+///
+/// Moves the content that follows it to the next of the paragraph's
+/// @par.tab-stops[tab stops] that lies beyond the current position in the
+/// line. Depending on the stop's alignment, the following content starts,
+/// ends, or is centered at the stop. If there is no further tab stop in the
+/// line, the tab has no effect.
+///
+/// Spaces next to a tab are collapsed.
+///
+/// = Example <example>
+/// ```example
+/// #set par(tab-stops: (
+///   3cm,
+///   (pos: 6cm, align: right, leader: [.]),
+/// ))
+///
+/// Introduction #tab() Intro #tab() 1 \
+/// Methods #tab() Method #tab() 12 \
+/// Results #tab() Result #tab() 123
+/// ```
+#[elem(title = "Tab")]
+pub struct TabElem {}
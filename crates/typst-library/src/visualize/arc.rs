@@ -0,0 +1,100 @@
+use crate::foundations::{IntoValue, Smart, cast, elem};
+use crate::layout::{Abs, Angle, Axes, Length};
+use crate::visualize::{Paint, Stroke};
+
+/// A circular or elliptical arc.
+///
+/// The arc runs along the outline of a circle or ellipse from the `start` to
+/// the `stop` angle. Angles are measured clockwise from the positive x-axis,
+/// i.e. `{0deg}` points to the right and `{90deg}` points down.
+///
+/// An arc always takes up the space of its full circle or ellipse. This way,
+/// multiple arcs with the same radius line up when placed on top of each
+/// other, for example to build a pie chart.
+///
+/// = Example <example>
+/// ```example
+/// #arc(radius: 20pt, start: 0deg, stop: 270deg)
+/// #arc(
+///   radius: 20pt,
+///   start: -90deg,
+///   stop: 45deg,
+///   pie: true,
+///   fill: blue.lighten(60%),
+/// )
+/// #arc(
+///   radius: (30pt, 15pt),
+///   start: 180deg,
+///   stop: 360deg,
+///   fill: red.lighten(60%),
+/// )
+/// ```
+#[elem]
+pub struct ArcElem {
+    /// The radius of the arc. Either a single length for a circular arc or an
+    /// array of a horizontal and a vertical radius for an elliptical arc. The
+    /// radii must not be negative.
+    #[default(ArcRadius(Axes::splat(Abs::pt(15.0).into())))]
+    pub radius: ArcRadius,
+
+    /// The angle at which the arc starts.
+    pub start: Angle,
+
+    /// The angle at which the arc stops. If it is smaller than the `start`
+    /// angle, the arc runs counterclockwise. An arc spans at most a full
+    /// circle, even if the angles are further apart.
+    #[default(Angle::deg(90.0))]
+    pub stop: Angle,
+
+    /// Whether to connect the ends of the arc with the center, creating a pie
+    /// sector.
+    ///
+    /// ```example
+    /// #let slice(start, stop, color) = place(arc(
+    ///   radius: 25pt,
+    ///   start: start,
+    ///   stop: stop,
+    ///   pie: true,
+    ///   fill: color,
+    ///   stroke: white,
+    /// ))
+    ///
+    /// #box(width: 50pt, height: 50pt, {
+    ///   slice(0deg, 120deg, red)
+    ///   slice(120deg, 200deg, green)
+    ///   slice(200deg, 360deg, blue)
+    /// })
+    /// ```
+    #[default(false)]
+    pub pie: bool,
+
+    /// How to fill the arc.
+    ///
+    /// If the arc is not a pie sector, the fill covers the segment between the
+    /// arc and the straight line connecting its ends. When setting a fill, the
+    /// default stroke disappears. To create an arc with both fill and stroke,
+    /// you have to configure both.
+    pub fill: Option<Paint>,
+
+    /// How to @stroke[stroke] the arc.
+    ///
+    /// Can be set to `{none}` to disable the stroke or to `{auto}` for a stroke
+    /// of `{1pt}` black if and only if no fill is given.
+    #[fold]
+    pub stroke: Smart<Option<Stroke>>,
+}
+
+/// The horizontal and vertical radius of an arc.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct ArcRadius(pub Axes<Length>);
+
+cast! {
+    ArcRadius,
+    self => if self.0.x == self.0.y {
+        self.0.x.into_value()
+    } else {
+        self.0.into_value()
+    },
+    v: Length => Self(Axes::splat(v)),
+    v: Axes<Length> => Self(v),
+}
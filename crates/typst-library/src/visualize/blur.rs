@@ -0,0 +1,37 @@
+use crate::foundations::{Content, elem};
+use crate::layout::Length;
+
+/// Blurs content.
+///
+/// The content is blurred as a whole with a Gaussian blur. This is useful for
+/// frosted backgrounds and for de-emphasizing parts of a document. In SVG
+/// export, the blur is applied by the viewer. In PNG and PDF export, the
+/// content is rasterized, so blurred text is no longer selectable as such.
+///
+/// The blur spills over the edges of the content by about three times the
+/// radius, but does not affect the layout.
+///
+/// = Example <example>
+/// ```example
+/// #set page(height: 80pt)
+/// #place(center + horizon, blur(
+///   3pt,
+///   text(32pt, blue)[BLURRY],
+/// ))
+///
+/// #blur(0.5pt)[Slightly out of focus.]
+/// ```
+#[elem]
+pub struct BlurElem {
+    /// The radius of the blur.
+    ///
+    /// This is the standard deviation of the Gaussian blur, like for the CSS
+    /// `blur` filter. A radius of `{0pt}` leaves the content unchanged. Must
+    /// not be negative.
+    #[required]
+    pub radius: Length,
+
+    /// The content to blur.
+    #[required]
+    pub body: Content,
+}
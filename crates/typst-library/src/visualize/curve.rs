@@ -1,10 +1,12 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
 use kurbo::Shape as _;
 use typst_macros::{Cast, scope};
 use typst_utils::Numeric;
 
 use crate::diag::{HintedStrResult, HintedString, bail};
 use crate::foundations::{Content, Packed, Smart, cast, elem};
-use crate::layout::{Abs, Axes, Length, Point, Rect, Rel, Size};
+use crate::layout::{Abs, Angle, Axes, Length, Point, Rect, Rel, Size};
 use crate::visualize::{FillRule, Paint, Stroke};
 
 use super::FixedStroke;
@@ -431,6 +433,34 @@ impl Curve {
         curve
     }
 
+    /// Creates a curve that describes an arc of an axis-aligned ellipse with
+    /// the given center and radii.
+    ///
+    /// Angles are measured clockwise from the positive x-axis. The curve
+    /// starts with a move to the start point of the arc. Sweeps of more than
+    /// a full turn are clamped to a full turn.
+    pub fn arc(center: Point, radius: Size, start: Angle, stop: Angle) -> Self {
+        let point = |a: f64| center + Point::new(radius.x * a.cos(), radius.y * a.sin());
+
+        // Each cubic segment approximates at most a quarter of the ellipse.
+        let (start, stop) = (start.to_rad(), stop.to_rad());
+        let sweep = (stop - start).clamp(-TAU, TAU);
+        let n = (sweep.abs() / FRAC_PI_2).ceil().max(1.0);
+        let step = sweep / n;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let tangent =
+            |a: f64| Point::new(-radius.x * a.sin() * k, radius.y * a.cos() * k);
+
+        let mut curve = Curve::new();
+        curve.move_(point(start));
+        for i in 0..n as usize {
+            let a = start + i as f64 * step;
+            let b = a + step;
+            curve.cubic(point(a) + tangent(a), point(b) - tangent(b), point(b));
+        }
+        curve
+    }
+
     /// Push a [`Move`](CurveItem::Move) item.
     pub fn move_(&mut self, p: Point) {
         self.0.push(CurveItem::Move(p));
@@ -554,3 +584,18 @@ impl Curve {
 fn point_to_kurbo(point: Point) -> kurbo::Point {
     kurbo::Point::new(point.x.to_raw(), point.y.to_raw())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_sweep_is_clamped() {
+        let radius = Size::splat(Abs::pt(10.0));
+        let arc = Curve::arc(Point::zero(), radius, Angle::zero(), Angle::deg(1e6));
+        assert_eq!(arc.0.len(), 5);
+        let CurveItem::Cubic(.., end) = arc.0[4] else { panic!() };
+        assert!((end.x.to_pt() - 10.0).abs() < 1e-6);
+        assert!(end.y.to_pt().abs() < 1e-6);
+    }
+}
@@ -25,7 +25,9 @@ use crate::foundations::{
     Bytes, Cast, Derived, Packed, Smart, StyleChain, Synthesize, cast, elem,
 };
 use crate::introspection::{Locatable, Tagged};
-use crate::layout::{Length, Rel, Sizing};
+use crate::layout::{
+    Alignment, HAlignment, Length, Ratio, Rel, Sides, Sizing, VAlignment,
+};
 use crate::loading::{DataSource, Load, Loaded};
 use crate::model::Figurable;
 use crate::text::{LocalName, Locale, families};
@@ -83,7 +85,9 @@ pub struct ImageElem {
     /// format automatically, but that's not always possible).
     ///
     /// Supported formats are `{"png"}`, `{"jpg"}`, `{"gif"}`, `{"svg"}`,
-    /// `{"pdf"}`, `{"webp"}` as well as raw pixel data.
+    /// `{"pdf"}`, `{"webp"}` as well as raw pixel data. Builds of Typst with
+    /// the `tiff` feature also support `{"tiff"}`. JPEG XL images are not
+    /// supported yet.
     ///
     /// Note that several restrictions apply when using PDF files as images:
     ///
@@ -174,6 +178,42 @@ pub struct ImageElem {
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
 
+    /// Which part of the image to keep in view when it doesn't fill its area
+    /// exactly.
+    ///
+    /// With `{fit: "cover"}`, this determines which part of the image is
+    /// cropped away. With `{fit: "contain"}`, it determines where in the area
+    /// the image is placed.
+    ///
+    /// ```example
+    /// #set page(width: 300pt, height: 60pt, margin: 10pt)
+    /// #set image(width: 30%, height: 100%)
+    /// #image("tiger.jpg", focus: left)
+    /// #image("tiger.jpg")
+    /// #image("tiger.jpg", focus: right)
+    /// ```
+    #[fold]
+    #[default(HAlignment::Center + VAlignment::Horizon)]
+    pub focus: Alignment,
+
+    /// How much of the image to trim away on each side before fitting it into
+    /// its area, relative to the image's size.
+    ///
+    /// This is useful to cut away borders or to select a part of a larger
+    /// image without preprocessing it. The remaining part of the image is
+    /// then sized and fitted as if it were the whole image.
+    ///
+    /// ```example
+    /// #set page(width: 300pt, height: 90pt, margin: 10pt)
+    /// #image("tiger.jpg", height: 100%)
+    /// #image(
+    ///   "tiger.jpg",
+    ///   height: 100%,
+    ///   crop: (left: 30%, right: 20%, top: 10%),
+    /// )
+    /// ```
+    pub crop: Sides<Option<Ratio>>,
+
     /// A hint to viewers how they should scale the image.
     ///
     /// When set to `{auto}`, the default is left up to the viewer. For PNG
@@ -197,6 +237,20 @@ pub struct ImageElem {
     })]
     pub icc: Smart<Derived<DataSource, Bytes>>,
 
+    /// A color filter to apply to the image.
+    ///
+    /// This is useful to de-emphasize images or to give them a uniform look
+    /// without preprocessing them. Filters are only supported for raster
+    /// images.
+    ///
+    /// ```example
+    /// #set image(width: 30%)
+    /// #image("tiger.jpg")
+    /// #image("tiger.jpg", filter: "grayscale")
+    /// #image("tiger.jpg", filter: "sepia")
+    /// ```
+    pub filter: Option<ImageFilter>,
+
     /// The locale of this element (used for the alternative description).
     #[internal]
     #[synthesized]
@@ -218,15 +272,23 @@ impl Packed<ImageElem> {
         let format = self.determine_format(styles).at(span)?;
 
         // Construct the image itself.
+        let filter = self.filter.get(styles);
         let kind = match format {
-            ImageFormat::Raster(format) => ImageKind::Raster(
-                RasterImage::new(
+            ImageFormat::Raster(format) => {
+                let raster = RasterImage::new(
                     loaded.data.clone(),
                     format,
                     self.icc.get_ref(styles).as_ref().map(|icc| icc.derived.clone()),
                 )
-                .at(span)?,
-            ),
+                .at(span)?;
+                ImageKind::Raster(match filter {
+                    Some(filter) => raster.filtered(filter),
+                    None => raster,
+                })
+            }
+            ImageFormat::Vector(_) if filter.is_some() => {
+                bail!(span, "image filters are only supported for raster images");
+            }
             ImageFormat::Vector(VectorFormat::Svg) => {
                 // Warn the user if the image contains a foreign object. Not
                 // perfect because the svg could also be encoded, but that's an
@@ -341,7 +403,15 @@ impl Packed<ImageElem> {
             return Ok(format);
         }
 
-        Ok(ImageFormat::detect(&loaded.data).ok_or("unknown image format")?)
+        if let Some(format) = ImageFormat::detect(&loaded.data) {
+            return Ok(format);
+        }
+
+        if is_jxl(&loaded.data) {
+            bail!("JPEG XL images are not supported yet");
+        }
+
+        bail!("unknown image format")
     }
 }
 
@@ -353,6 +423,7 @@ fn determine_format_from_path(path: &VirtualPath) -> Option<ImageFormat> {
         "jpg" | "jpeg" => Some(ExchangeFormat::Jpg.into()),
         "gif" => Some(ExchangeFormat::Gif.into()),
         "webp" => Some(ExchangeFormat::Webp.into()),
+        "tif" | "tiff" => Some(ExchangeFormat::Tiff.into()),
         // Vector formats
         "svg" | "svgz" => Some(VectorFormat::Svg.into()),
         "pdf" => Some(VectorFormat::Pdf.into()),
@@ -548,6 +619,11 @@ impl ImageFormat {
     }
 }
 
+/// Checks whether the data looks like a JPEG XL codestream or container.
+fn is_jxl(data: &[u8]) -> bool {
+    data.starts_with(&[0xff, 0x0a]) || data.starts_with(b"\0\0\0\x0cJXL \r\n\x87\n")
+}
+
 /// Checks whether the data looks like a PDF file.
 fn is_pdf(data: &[u8]) -> bool {
     let head = &data[..data.len().min(2048)];
@@ -604,6 +680,15 @@ cast! {
     v: VectorFormat => Self::Vector(v),
 }
 
+/// A color filter for an image.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ImageFilter {
+    /// Removes all color, keeping only the luminance.
+    Grayscale,
+    /// Tints the image in brownish tones, like an old photograph.
+    Sepia,
+}
+
 /// The image scaling algorithm a viewer should use.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum ImageScaling {
@@ -5,14 +5,19 @@ use std::sync::Arc;
 
 use crate::diag::{StrResult, bail};
 use crate::foundations::{Bytes, Cast, Dict, Smart, Value, cast, dict};
+use crate::visualize::ImageFilter;
 use ecow::{EcoString, eco_format};
 use image::codecs::gif::GifDecoder;
 use image::codecs::jpeg::JpegDecoder;
 use image::codecs::png::PngDecoder;
+#[cfg(feature = "tiff")]
+use image::codecs::tiff::TiffDecoder;
 use image::codecs::webp::WebPDecoder;
 use image::{
-    DynamicImage, ImageBuffer, ImageDecoder, ImageResult, Limits, Pixel, guess_format,
+    DynamicImage, ImageBuffer, ImageDecoder, ImageResult, Limits, Pixel, RgbaImage,
+    guess_format,
 };
+use moxcms::{ColorProfile, DataColorSpace, Layout, TransformOptions};
 
 /// A decoded raster image.
 #[derive(Clone, Hash)]
@@ -79,6 +84,12 @@ impl RasterImage {
                     ExchangeFormat::Png => decode(PngDecoder::new(cursor), icc),
                     ExchangeFormat::Gif => decode(GifDecoder::new(cursor), icc),
                     ExchangeFormat::Webp => decode(WebPDecoder::new(cursor), icc),
+                    #[cfg(feature = "tiff")]
+                    ExchangeFormat::Tiff => decode(TiffDecoder::new(cursor), icc),
+                    #[cfg(not(feature = "tiff"))]
+                    ExchangeFormat::Tiff => {
+                        bail!("TIFF images are not supported in this build of Typst")
+                    }
                 }
                 .map_err(format_image_error)?;
 
@@ -195,6 +206,90 @@ impl RasterImage {
     pub fn icc(&self) -> Option<&Bytes> {
         self.0.icc.as_ref()
     }
+
+    /// Apply a color filter to the image.
+    ///
+    /// The image's ICC profile is applied before filtering, so the result
+    /// consists of raw sRGB pixel data without a profile. It retains the
+    /// image's pixel density.
+    #[comemo::memoize]
+    pub fn filtered(&self, filter: ImageFilter) -> RasterImage {
+        let rgba = self.to_srgb_rgba8();
+        let dynamic: DynamicImage = match filter {
+            ImageFilter::Grayscale => DynamicImage::from(rgba).to_luma_alpha8().into(),
+            ImageFilter::Sepia => {
+                let mut buf = rgba;
+                for pixel in buf.pixels_mut() {
+                    let [r, g, b, _] = pixel.0.map(f32::from);
+                    let tint = |wr: f32, wg: f32, wb: f32| {
+                        (wr * r + wg * g + wb * b).round().min(255.0) as u8
+                    };
+                    pixel.0[0] = tint(0.393, 0.769, 0.189);
+                    pixel.0[1] = tint(0.349, 0.686, 0.168);
+                    pixel.0[2] = tint(0.272, 0.534, 0.131);
+                }
+                buf.into()
+            }
+        };
+
+        let encoding = match filter {
+            ImageFilter::Grayscale => PixelEncoding::Lumaa8,
+            ImageFilter::Sepia => PixelEncoding::Rgba8,
+        };
+
+        let format = PixelFormat {
+            encoding,
+            width: dynamic.width(),
+            height: dynamic.height(),
+        };
+
+        Self(Arc::new(RasterImageInner {
+            data: Bytes::new(dynamic.as_bytes().to_vec()),
+            format: RasterFormat::Pixel(format),
+            exif_rotation: None,
+            dynamic: Arc::new(dynamic),
+            icc: None,
+            dpi: self.dpi(),
+        }))
+    }
+
+    /// Converts the image's pixels to sRGB by applying its ICC profile.
+    ///
+    /// Falls back to the unconverted pixels if there is no profile or if it
+    /// doesn't match the image's color type.
+    fn to_srgb_rgba8(&self) -> RgbaImage {
+        let mut rgba = self.dynamic().to_rgba8();
+        let Some(profile) =
+            self.icc().and_then(|icc| ColorProfile::new_from_slice(icc).ok())
+        else {
+            return rgba;
+        };
+
+        let has_color = self.dynamic().color().has_color();
+        let (src, layout) = match profile.color_space {
+            DataColorSpace::Rgb if has_color => (rgba.as_raw().clone(), Layout::Rgba),
+            DataColorSpace::Gray if !has_color => {
+                (self.dynamic().to_luma_alpha8().into_raw(), Layout::GrayAlpha)
+            }
+            _ => return rgba,
+        };
+
+        let srgb = ColorProfile::new_srgb();
+        let mut dst = vec![0; rgba.as_raw().len()];
+        let converted = profile
+            .create_transform_8bit(
+                layout,
+                &srgb,
+                Layout::Rgba,
+                TransformOptions::default(),
+            )
+            .and_then(|transform| transform.transform(&src, &mut dst));
+        if converted.is_ok() {
+            rgba.copy_from_slice(&dst);
+        }
+
+        rgba
+    }
 }
 
 impl Hash for RasterImageInner {
@@ -249,6 +344,9 @@ pub enum ExchangeFormat {
     Gif,
     /// Raster format that supports both lossy and lossless compression.
     Webp,
+    /// Raster format common in scanning and print workflows. Decoding TIFF
+    /// images requires Typst to be built with the `tiff` feature.
+    Tiff,
 }
 
 impl ExchangeFormat {
@@ -265,6 +363,7 @@ impl From<ExchangeFormat> for image::ImageFormat {
             ExchangeFormat::Jpg => image::ImageFormat::Jpeg,
             ExchangeFormat::Gif => image::ImageFormat::Gif,
             ExchangeFormat::Webp => image::ImageFormat::WebP,
+            ExchangeFormat::Tiff => image::ImageFormat::Tiff,
         }
     }
 }
@@ -278,6 +377,7 @@ impl TryFrom<image::ImageFormat> for ExchangeFormat {
             image::ImageFormat::Jpeg => ExchangeFormat::Jpg,
             image::ImageFormat::Gif => ExchangeFormat::Gif,
             image::ImageFormat::WebP => ExchangeFormat::Webp,
+            image::ImageFormat::Tiff => ExchangeFormat::Tiff,
             _ => bail!("format not yet supported"),
         })
     }
@@ -470,4 +570,28 @@ mod tests {
         test("images/tiger.jpg", ExchangeFormat::Jpg, 72.0);
         test("images/graph.png", ExchangeFormat::Png, 144.0);
     }
+
+    #[test]
+    #[cfg(feature = "tiff")]
+    fn test_image_tiff() {
+        let mut data = vec![];
+        let pixels = [255, 0, 0, 0, 0, 255];
+        image::codecs::tiff::TiffEncoder::new(io::Cursor::new(&mut data))
+            .encode(&pixels, 2, 1, image::ExtendedColorType::Rgb8)
+            .unwrap();
+
+        let image = RasterImage::plain(Bytes::new(data), ExchangeFormat::Tiff).unwrap();
+        assert_eq!((image.width(), image.height()), (2, 1));
+        assert_eq!(ExchangeFormat::detect(image.data()), Some(ExchangeFormat::Tiff));
+    }
+
+    #[test]
+    #[cfg(not(feature = "tiff"))]
+    fn test_image_tiff_unsupported() {
+        let data = Bytes::new(b"II*\0".to_vec());
+        assert_eq!(
+            RasterImage::plain(data, ExchangeFormat::Tiff).err().as_deref(),
+            Some("TIFF images are not supported in this build of Typst"),
+        );
+    }
 }
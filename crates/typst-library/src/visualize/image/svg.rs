@@ -413,6 +413,9 @@ impl<'a> ImageResolver<'a> {
                                 ExchangeFormat::Webp => {
                                     Ok(usvg::ImageKind::WEBP(arc_data))
                                 }
+                                ExchangeFormat::Tiff => {
+                                    Err("TIFF images are not supported in SVGs".into())
+                                }
                             }
                         }
                         RasterFormat::Pixel(_) => {
@@ -1,4 +1,4 @@
-use crate::foundations::elem;
+use crate::foundations::{Cast, Dict, IntoValue, Smart, Value, cast, elem};
 use crate::layout::{Abs, Angle, Axes, Length, Rel};
 use crate::visualize::Stroke;
 
@@ -45,6 +45,84 @@ pub struct LineElem {
     ///   line(stroke: (paint: blue, thickness: 1pt, dash: ("dot", 2pt, 4pt, 2pt))),
     /// )
     /// ```
+    ///
+    /// The stroke's `join` also applies to open arrowhead markers.
     #[fold]
     pub stroke: Stroke,
+
+    /// A marker to draw at the start of the line.
+    ///
+    /// Can be the name of a marker kind or a dictionary with the keys `kind`
+    /// and `size`. If the size is `{auto}` or not given, it is derived from
+    /// the thickness of the stroke. The following kinds are available:
+    ///
+    /// - `{"arrow"}`: A filled arrowhead.
+    /// - `{"chevron"}`: An open arrowhead, drawn with the line's stroke.
+    /// - `{"circle"}`: A filled circle centered on the end point.
+    /// - `{"bar"}`: A bar perpendicular to the line.
+    ///
+    /// ```example
+    /// #set line(length: 100%)
+    /// #stack(
+    ///   spacing: 1em,
+    ///   line(start-marker: "circle", end-marker: "arrow"),
+    ///   line(start-marker: "bar", end-marker: "bar"),
+    ///   line(
+    ///     stroke: (thickness: 2pt, join: "round"),
+    ///     end-marker: (kind: "chevron", size: 8pt),
+    ///   ),
+    /// )
+    /// ```
+    pub start_marker: Option<LineMarker>,
+
+    /// A marker to draw at the end of the line.
+    ///
+    /// Takes the same values as the `start-marker`.
+    ///
+    /// ```example
+    /// #line(end: (50pt, 20pt), end-marker: "arrow")
+    /// ```
+    pub end_marker: Option<LineMarker>,
+}
+
+/// A marker at one end of a line.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct LineMarker {
+    /// What the marker looks like.
+    pub kind: LineMarkerKind,
+    /// The length of the marker along the line.
+    pub size: Smart<Length>,
+}
+
+cast! {
+    LineMarker,
+    self => match self.size {
+        Smart::Auto => self.kind.into_value(),
+        Smart::Custom(size) => {
+            let mut dict = Dict::new();
+            dict.insert("kind".into(), self.kind.into_value());
+            dict.insert("size".into(), size.into_value());
+            Value::Dict(dict)
+        }
+    },
+    kind: LineMarkerKind => Self { kind, size: Smart::Auto },
+    mut dict: Dict => {
+        let kind = dict.take("kind")?.cast()?;
+        let size = dict.take("size").ok().map(Value::cast).transpose()?;
+        dict.finish(&["kind", "size"])?;
+        Self { kind, size: size.unwrap_or_default() }
+    },
+}
+
+/// The shape of a line marker.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum LineMarkerKind {
+    /// A filled arrowhead.
+    Arrow,
+    /// An open arrowhead.
+    Chevron,
+    /// A filled circle.
+    Circle,
+    /// A bar perpendicular to the line.
+    Bar,
 }
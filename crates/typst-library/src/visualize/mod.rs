@@ -1,23 +1,31 @@
 //! Drawing and visualization.
 
+mod arc;
+mod blur;
 mod color;
 mod curve;
 mod gradient;
 mod image;
 mod line;
+mod opacity;
 mod paint;
 mod polygon;
+mod shadow;
 mod shape;
 mod stroke;
 mod tiling;
 
+pub use self::arc::*;
+pub use self::blur::*;
 pub use self::color::*;
 pub use self::curve::*;
 pub use self::gradient::*;
 pub use self::image::*;
 pub use self::line::*;
+pub use self::opacity::*;
 pub use self::paint::*;
 pub use self::polygon::*;
+pub use self::shadow::*;
 pub use self::shape::*;
 pub use self::stroke::*;
 pub use self::tiling::*;
@@ -39,5 +47,8 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<CircleElem>();
     global.define_elem::<PolygonElem>();
     global.define_elem::<CurveElem>();
+    global.define_elem::<ArcElem>();
+    global.define_elem::<OpacityElem>();
+    global.define_elem::<BlurElem>();
     global.reset_category();
 }
@@ -0,0 +1,49 @@
+use crate::diag::bail;
+use crate::foundations::{Content, IntoValue, cast, elem};
+use crate::layout::Ratio;
+
+/// Makes content translucent.
+///
+/// The content is composited as a whole: Overlapping parts of it do not shine
+/// through each other. This is useful for watermarks and overlays in
+/// diagrams. To make just a fill or stroke translucent, you can instead use a
+/// color with an alpha component, e.g. via
+/// @color.transparentize[`transparentize`].
+///
+/// = Example <example>
+/// ```example
+/// #set page(height: 80pt)
+/// #place(center + horizon, opacity(
+///   20%,
+///   rotate(-20deg, text(32pt, red)[DRAFT]),
+/// ))
+///
+/// This document is not final.
+/// #opacity(50%)[Some faded text.]
+/// ```
+#[elem]
+pub struct OpacityElem {
+    /// How opaque the content is, from `{0%}` (invisible) to `{100%}` (fully
+    /// opaque).
+    #[positional]
+    #[default(Opacity(Ratio::one()))]
+    pub amount: Opacity,
+
+    /// The content to make translucent.
+    #[required]
+    pub body: Content,
+}
+
+/// An opacity between `{0%}` and `{100%}`.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct Opacity(pub Ratio);
+
+cast! {
+    Opacity,
+    self => self.0.into_value(),
+    v: Ratio => if (0.0..=1.0).contains(&v.get()) {
+        Self(v)
+    } else {
+        bail!("opacity must be between 0% and 100%");
+    },
+}
@@ -0,0 +1,57 @@
+use crate::foundations::{Dict, IntoValue, Value, cast};
+use crate::layout::{Abs, Length};
+use crate::visualize::Color;
+
+/// A soft shadow behind a shape or container.
+///
+/// Can be given as a color or as a dictionary with the keys `dx`, `dy`,
+/// `blur`, and `color`. Missing keys take on their default values.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Shadow {
+    /// The horizontal offset of the shadow. Defaults to `{2pt}`.
+    pub dx: Length,
+    /// The vertical offset of the shadow. Defaults to `{2pt}`.
+    pub dy: Length,
+    /// How far the shadow's edge fades out. Defaults to `{4pt}`.
+    pub blur: Length,
+    /// The color of the shadow. Defaults to a translucent black.
+    pub color: Color,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            dx: Abs::pt(2.0).into(),
+            dy: Abs::pt(2.0).into(),
+            blur: Abs::pt(4.0).into(),
+            color: Color::BLACK.with_alpha(0.4),
+        }
+    }
+}
+
+cast! {
+    Shadow,
+    self => {
+        let mut dict = Dict::new();
+        dict.insert("dx".into(), self.dx.into_value());
+        dict.insert("dy".into(), self.dy.into_value());
+        dict.insert("blur".into(), self.blur.into_value());
+        dict.insert("color".into(), self.color.into_value());
+        Value::Dict(dict)
+    },
+    color: Color => Self { color, ..Default::default() },
+    mut dict: Dict => {
+        let default = Self::default();
+        let dx = dict.take("dx").ok().map(Value::cast).transpose()?;
+        let dy = dict.take("dy").ok().map(Value::cast).transpose()?;
+        let blur = dict.take("blur").ok().map(Value::cast).transpose()?;
+        let color = dict.take("color").ok().map(Value::cast).transpose()?;
+        dict.finish(&["dx", "dy", "blur", "color"])?;
+        Self {
+            dx: dx.unwrap_or(default.dx),
+            dy: dy.unwrap_or(default.dy),
+            blur: blur.unwrap_or(default.blur),
+            color: color.unwrap_or(default.color),
+        }
+    },
+}
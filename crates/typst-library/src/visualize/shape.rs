@@ -1,6 +1,6 @@
 use crate::foundations::{Cast, Content, Smart, elem};
 use crate::layout::{Abs, Corners, Length, Point, Rect, Rel, Sides, Size, Sizing};
-use crate::visualize::{Curve, FixedStroke, Paint, Stroke};
+use crate::visualize::{Curve, FixedStroke, Paint, Shadow, Stroke};
 use kurbo::{PathEl, Shape as _};
 
 /// A rectangle with optional content.
@@ -119,6 +119,10 @@ pub struct RectElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A soft shadow to draw behind the rectangle. See the
+    /// @box.shadow[box's documentation] for more details.
+    pub shadow: Option<Shadow>,
+
     /// The content to place into the rectangle.
     ///
     /// When this is omitted, the rectangle takes on a default size of at most
@@ -195,6 +199,10 @@ pub struct SquareElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A soft shadow to draw behind the square. See the
+    /// @box.shadow[box's documentation] for more details.
+    pub shadow: Option<Shadow>,
+
     /// The content to place into the square. The square expands to fit this
     /// content, keeping the 1-1 aspect ratio.
     ///
@@ -246,6 +254,10 @@ pub struct EllipseElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A soft shadow to draw behind the ellipse. See the
+    /// @box.shadow[box's documentation] for more details.
+    pub shadow: Option<Shadow>,
+
     /// The content to place into the ellipse.
     ///
     /// When this is omitted, the ellipse takes on a default size of at most
@@ -323,6 +335,10 @@ pub struct CircleElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A soft shadow to draw behind the circle. See the
+    /// @box.shadow[box's documentation] for more details.
+    pub shadow: Option<Shadow>,
+
     /// The content to place into the circle. The circle expands to fit this
     /// content, keeping the 1-1 aspect ratio.
     #[positional]
@@ -9,6 +9,7 @@ use krilla::destination::NamedDestination;
 use krilla::embed::EmbedError;
 use krilla::error::{KrillaError, LimitError};
 use krilla::geom::{PathBuilder, Rect};
+use krilla::num::NormalizedF32;
 use krilla::page::{PageLabel, PageSettings};
 use krilla::pdf::PdfError;
 use krilla::surface::Surface;
@@ -31,7 +32,7 @@ use typst_syntax::Span;
 
 use crate::PdfOptions;
 use crate::attach::attach_files;
-use crate::image::handle_image;
+use crate::image::{handle_blurred, handle_image};
 use crate::link::{LinkAnnotation, handle_link};
 use crate::metadata::build_metadata;
 use crate::outline::build_outline;
@@ -101,27 +102,31 @@ fn convert_pages(gc: &mut GlobalContext, document: &mut Document) -> SourceResul
             continue;
         }
 
+        // The page's bleed and the space for printer's marks around it.
+        let bleed = typst_page.bleed;
+        let slug = typst_page.slug;
+        let boxes = PageBoxes::new(typst_page.frame.size(), bleed, slug);
+
         // PDF 1.4 upwards to 1.7 specifies a minimum page size of 3x3 units.
         // PDF 2.0 doesn't define an explicit limit, but krilla and probably
         // some viewers won't handle pages that have zero sized pages.
         let mut settings = PageSettings::from_wh(
-            (typst_page.frame.width() + typst_page.bleed.left + typst_page.bleed.right)
-                .to_f32()
-                .max(3.0),
-            (typst_page.frame.height() + typst_page.bleed.top + typst_page.bleed.bottom)
-                .to_f32()
-                .max(3.0),
+            boxes.media.x.to_f32().max(3.0),
+            boxes.media.y.to_f32().max(3.0),
         )
         .expect_internal("invalid page size")
         .at(Span::detached())?;
 
-        if !typst_page.bleed.is_zero() {
-            settings = settings.with_trim_box(Rect::from_ltrb(
-                typst_page.bleed.left.to_f32(),
-                typst_page.bleed.top.to_f32(),
-                (typst_page.bleed.left + typst_page.frame.width()).to_f32(),
-                (typst_page.bleed.top + typst_page.frame.height()).to_f32(),
-            ));
+        let rect = |[left, top, right, bottom]: [Abs; 4]| {
+            Rect::from_ltrb(left.to_f32(), top.to_f32(), right.to_f32(), bottom.to_f32())
+        };
+
+        if let Some(trim) = boxes.trim {
+            settings = settings.with_trim_box(rect(trim));
+        }
+
+        if let Some(bleed_box) = boxes.bleed {
+            settings = settings.with_bleed_box(rect(bleed_box));
         }
 
         if let Some(label) = typst_page
@@ -147,16 +152,17 @@ fn convert_pages(gc: &mut GlobalContext, document: &mut Document) -> SourceResul
         let mut page = document.start_page_with(settings);
         let mut surface = page.surface();
         let page_idx = gc.page_index_converter.pdf_page_index(i);
-        let mut fc = FrameContext::new(
-            page_idx,
-            typst_page.frame.size() + typst_page.bleed.sum_by_axis(),
-        );
+        let mut fc = FrameContext::new(page_idx, boxes.media);
+
+        // Printer's marks are drawn by the frame itself, so we only need to
+        // move the bleed area inwards by the space reserved for them.
+        fc.state_mut().pre_concat(Transform::translate(slug, slug));
 
         tags::page(gc, &mut surface, |gc, surface| {
             handle_frame(
                 &mut fc,
                 &typst_page.frame,
-                typst_page.bleed,
+                bleed,
                 typst_page.fill_or_transparent(),
                 surface,
                 gc,
@@ -172,6 +178,36 @@ fn convert_pages(gc: &mut GlobalContext, document: &mut Document) -> SourceResul
     Ok(())
 }
 
+/// The boxes of a page in the PDF.
+///
+/// The trim and bleed boxes are given as `[left, top, right, bottom]` relative
+/// to the top left corner of the media box.
+#[derive(Debug, PartialEq)]
+struct PageBoxes {
+    /// The size of the media box, which includes the bleed and the space for
+    /// printer's marks around it.
+    media: Size,
+    /// The trimmed page, if it differs from the media box.
+    trim: Option<[Abs; 4]>,
+    /// The page including its bleed, if there are printer's marks around it.
+    bleed: Option<[Abs; 4]>,
+}
+
+impl PageBoxes {
+    /// Determines the boxes of a page with the given size, bleed, and space
+    /// for printer's marks.
+    fn new(size: Size, bleed: Sides<Abs>, slug: Abs) -> Self {
+        let extent = bleed.map(|side| side + slug);
+        let media = size + extent.sum_by_axis();
+        let trim = (!extent.is_zero()).then(|| {
+            [extent.left, extent.top, extent.left + size.x, extent.top + size.y]
+        });
+        let bleed =
+            (!slug.is_zero()).then(|| [slug, slug, media.x - slug, media.y - slug]);
+        Self { media, trim, bleed }
+    }
+}
+
 /// A state allowing us to keep track of transforms and container sizes,
 /// which is mainly needed to resolve gradients and patterns correctly.
 #[derive(Debug, Clone)]
@@ -414,9 +450,32 @@ pub(crate) fn handle_group(
             surface.push_clip_path(clip_path, &krilla::paint::FillRule::NonZero);
         }
 
+        let translucent = !group.opacity.is_one();
+        if translucent {
+            let opacity = group.opacity.get().clamp(0.0, 1.0) as f32;
+            surface.push_opacity(NormalizedF32::new(opacity).unwrap());
+        }
+
+        // The blurred contents are drawn as an image. The original contents
+        // are still written, but invisibly, so that their text stays
+        // extractable and tagged.
+        let blurred = group.blur > Abs::zero();
+        if blurred {
+            handle_blurred(gc, fc, &group.frame, group.blur, surface, Span::detached())?;
+            surface.push_opacity(NormalizedF32::new(0.0).unwrap());
+        }
+
         let res =
             handle_frame(fc, &group.frame, Sides::splat(Abs::zero()), None, surface, gc);
 
+        if blurred {
+            surface.pop();
+        }
+
+        if translucent {
+            surface.pop();
+        }
+
         if clip_path.is_some() {
             surface.pop();
         }
@@ -918,3 +977,42 @@ impl PageIndexConverter {
         self.page_indices.get(&page_index).copied()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_boxes() {
+        let pt = Abs::pt;
+        let size = Size::new(pt(100.0), pt(50.0));
+
+        // Without a bleed, the media box is just the page.
+        let zero = Sides::splat(Abs::zero());
+        assert_eq!(
+            PageBoxes::new(size, zero, Abs::zero()),
+            PageBoxes { media: size, trim: None, bleed: None },
+        );
+
+        // With a bleed, the trim box excludes it.
+        let bleed = Sides::new(pt(1.0), pt(2.0), pt(3.0), pt(4.0));
+        assert_eq!(
+            PageBoxes::new(size, bleed, Abs::zero()),
+            PageBoxes {
+                media: Size::new(pt(104.0), pt(56.0)),
+                trim: Some([pt(1.0), pt(2.0), pt(101.0), pt(52.0)]),
+                bleed: None,
+            },
+        );
+
+        // With printer's marks, the bleed box excludes the space for them.
+        assert_eq!(
+            PageBoxes::new(size, bleed, pt(10.0)),
+            PageBoxes {
+                media: Size::new(pt(124.0), pt(76.0)),
+                trim: Some([pt(11.0), pt(12.0), pt(111.0), pt(62.0)]),
+                bleed: Some([pt(10.0), pt(10.0), pt(114.0), pt(66.0)]),
+            },
+        );
+    }
+}
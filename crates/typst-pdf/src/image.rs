@@ -8,10 +8,11 @@ use krilla::pdf::PdfDocument;
 use krilla::surface::Surface;
 use krilla_svg::{SurfaceExt, SvgSettings};
 use typst_library::diag::{At, SourceResult};
-use typst_library::foundations::Smart;
-use typst_library::layout::{Abs, Angle, Ratio, Size, Transform};
+use typst_library::foundations::{Bytes, Smart};
+use typst_library::layout::{Abs, Angle, Frame, Ratio, Size, Transform};
 use typst_library::visualize::{
-    ExchangeFormat, Image, ImageKind, ImageScaling, PdfImage, RasterFormat, RasterImage,
+    ExchangeFormat, Image, ImageKind, ImageScaling, PdfImage, PixelEncoding, PixelFormat,
+    RasterFormat, RasterImage,
 };
 use typst_syntax::Span;
 use typst_utils::defer;
@@ -80,6 +81,73 @@ pub(crate) fn handle_image(
     Ok(())
 }
 
+/// The resolution at which blurred content is rasterized.
+///
+/// A blur removes fine detail anyway, so this can be lower than what would be
+/// needed for crisp content.
+const BLUR_PIXEL_PER_PT: f32 = 2.0;
+
+/// Draws a blurred version of a frame's contents.
+///
+/// PDF has no native blur, so the contents are rasterized, blurred, and
+/// embedded as an image.
+pub(crate) fn handle_blurred(
+    gc: &mut GlobalContext,
+    fc: &FrameContext,
+    frame: &Frame,
+    radius: Abs,
+    surface: &mut Surface,
+    span: Span,
+) -> SourceResult<()> {
+    let Some((pixmap, origin)) =
+        typst_render::render_blurred(frame, radius, BLUR_PIXEL_PER_PT)
+    else {
+        return Ok(());
+    };
+
+    // The pixmap is premultiplied, but raster images are not.
+    let data: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+
+    let format = PixelFormat {
+        encoding: PixelEncoding::Rgba8,
+        width: pixmap.width(),
+        height: pixmap.height(),
+    };
+
+    let raster = RasterImage::new(Bytes::new(data), format, Smart::Auto).at(span)?;
+    let image = convert_raster(raster, true)
+        .map_err(|err| eco_format!("failed to process blurred content ({err})"))
+        .at(span)?;
+
+    let scale = f64::from(BLUR_PIXEL_PER_PT);
+    let size = Size::new(
+        Abs::pt(f64::from(pixmap.width()) / scale),
+        Abs::pt(f64::from(pixmap.height()) / scale),
+    );
+
+    let mut handle = tags::blurred(gc, surface);
+    let surface = handle.surface();
+
+    let ts = fc
+        .state()
+        .transform()
+        .pre_concat(Transform::translate(origin.x, origin.y));
+    surface.push_transform(&ts.to_krilla());
+    if let Some(size) = size.to_krilla() {
+        surface.draw_image(image, size);
+    }
+    surface.pop();
+
+    Ok(())
+}
+
 /// A wrapper around `RasterImage` so that we can implement `CustomImage`.
 #[derive(Clone)]
 struct PdfRasterImage(Arc<PdfRasterImageInner>);
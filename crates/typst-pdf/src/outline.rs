@@ -65,7 +65,13 @@ fn convert_node(
         .unwrap_or(PagedPosition::ORIGIN);
 
     // Prepend the numbers to the title if they exist.
-    let text = node.entry.body.plain_text();
+    let text = node
+        .entry
+        .short
+        .get_ref(StyleChain::default())
+        .as_ref()
+        .unwrap_or(&node.entry.body)
+        .plain_text();
     let title = match &node.entry.numbers {
         Some(num) => format!("{num} {text}"),
         None => text.to_string(),
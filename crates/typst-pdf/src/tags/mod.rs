@@ -281,6 +281,21 @@ pub fn shape<'a, 'b>(
     TagHandle { surface, started: true }
 }
 
+/// Marks the rasterized pixels of blurred content as an artifact. The content
+/// itself is written invisibly alongside and tagged as usual.
+pub fn blurred<'a, 'b>(
+    gc: &mut GlobalContext,
+    surface: &'b mut Surface<'a>,
+) -> TagHandle<'a, 'b> {
+    if disabled(gc) || gc.tags.tree.parent_artifact().is_some() {
+        return TagHandle { surface, started: false };
+    }
+
+    surface.start_tagged(ContentTag::Artifact(Artifact::with_kind(ArtifactType::Layout)));
+
+    TagHandle { surface, started: true }
+}
+
 fn update_bbox(
     gc: &mut GlobalContext,
     fc: &FrameContext,
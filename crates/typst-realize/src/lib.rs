@@ -33,7 +33,7 @@ use typst_library::model::{
     ParElem, ParbreakElem, TermsElem,
 };
 use typst_library::routines::{Arenas, FragmentKind, Pair, RealizationKind};
-use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TabElem, TextElem};
 use typst_syntax::Span;
 use typst_utils::{ListSet, SliceExt, SmallBitSet};
 
@@ -419,7 +419,10 @@ fn visit_show_rules<'a>(
     let prev_outside = s.outside;
     s.outside &= content.is::<ContextElem>();
     s.engine.route.increase();
-    s.engine.route.check_show_depth().at(content.span())?;
+    s.engine
+        .route
+        .check_show_depth(&s.engine.library.depth_limits)
+        .at(content.span())?;
 
     visit_styled(s, realized, Cow::Owned(map), styles)?;
 
@@ -1051,6 +1054,7 @@ static PAR: GroupingRule = GroupingRule {
         if elem == TextElem::ELEM
             || elem == HElem::ELEM
             || elem == LinebreakElem::ELEM
+            || elem == TabElem::ELEM
             || elem == SmartQuoteElem::ELEM
             || elem == InlineElem::ELEM
             || elem == BoxElem::ELEM
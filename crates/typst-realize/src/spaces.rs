@@ -5,7 +5,7 @@ use typst_library::foundations::{Content, StyleChain};
 use typst_library::introspection::TagElem;
 use typst_library::layout::HElem;
 use typst_library::routines::Pair;
-use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TabElem, TextElem};
 
 /// State kept for space collapsing.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -85,6 +85,7 @@ pub(crate) fn collapse_state(content: &Content, styles: StyleChain) -> SpaceStat
             SpaceState::Invisible
         }
     } else if content.is::<LinebreakElem>()
+        || content.is::<TabElem>()
         // We want to collapse spaces that would otherwise be protected and show
         // up as spans with `white-space: pre-wrap`.
         || content.to_packed::<HtmlElem>().is_some_and(|elem| {
@@ -0,0 +1,86 @@
+use tiny_skia as sk;
+
+/// Blurs a pixmap with a Gaussian blur of the given standard deviation in
+/// pixels.
+///
+/// The Gaussian is approximated by three successive box blurs in each
+/// direction. Pixels outside of the pixmap are treated as transparent.
+pub fn blur(pixmap: &mut sk::Pixmap, sigma: f32) {
+    if sigma <= 0.0 {
+        return;
+    }
+
+    let (w, h) = (pixmap.width() as usize, pixmap.height() as usize);
+    let data = pixmap.data_mut();
+    let mut buf = vec![0; data.len()];
+    for size in box_sizes(sigma) {
+        let radius = (size - 1) / 2;
+        box_blur(data, &mut buf, h, w, 4 * w, 4, radius);
+        box_blur(&buf, data, w, h, 4, 4 * w, radius);
+    }
+}
+
+/// Computes the widths of three box blurs whose succession approximates a
+/// Gaussian blur with the given standard deviation.
+///
+/// See <https://www.peterkovesi.com/papers/FastGaussianSmoothing.pdf>.
+fn box_sizes(sigma: f32) -> [usize; 3] {
+    let n = 3.0;
+    let variance = sigma * sigma;
+    let ideal = (12.0 * variance / n + 1.0).sqrt();
+    let mut lower = ideal.floor() as usize;
+    if lower % 2 == 0 {
+        lower -= 1;
+    }
+
+    let l = lower as f32;
+    let m = ((12.0 * variance - n * l * l - 4.0 * n * l - 3.0 * n) / (-4.0 * l - 4.0))
+        .round() as usize;
+    std::array::from_fn(|i| if i < m { lower } else { lower + 2 })
+}
+
+/// Blurs each line of premultiplied RGBA pixels with a box of the given
+/// radius.
+///
+/// Line `i` starts at byte `i * stride` and consecutive pixels within a line
+/// are `step` bytes apart.
+fn box_blur(
+    src: &[u8],
+    dst: &mut [u8],
+    lines: usize,
+    len: usize,
+    stride: usize,
+    step: usize,
+    radius: usize,
+) {
+    let div = 2 * radius as u32 + 1;
+    for line in 0..lines {
+        let at = |i: usize| line * stride + i * step;
+        let mut sum = [0u32; 4];
+        for i in 0..radius.min(len) {
+            for (s, &v) in sum.iter_mut().zip(&src[at(i)..at(i) + 4]) {
+                *s += u32::from(v);
+            }
+        }
+
+        for i in 0..len {
+            if i + radius < len {
+                let j = at(i + radius);
+                for (s, &v) in sum.iter_mut().zip(&src[j..j + 4]) {
+                    *s += u32::from(v);
+                }
+            }
+
+            for (d, &s) in dst[at(i)..at(i) + 4].iter_mut().zip(&sum) {
+                *d = ((s + div / 2) / div) as u8;
+            }
+
+            if i >= radius {
+                let j = at(i - radius);
+                for (s, &v) in sum.iter_mut().zip(&src[j..j + 4]) {
+                    *s -= u32::from(v);
+                }
+            }
+        }
+    }
+}
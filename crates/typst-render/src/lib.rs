@@ -1,5 +1,6 @@
 //! Rendering of Typst documents into raster images.
 
+mod blur;
 mod image;
 mod paint;
 mod shape;
@@ -8,7 +9,8 @@ mod text;
 use tiny_skia as sk;
 use typst_layout::{Page, PagedDocument};
 use typst_library::layout::{
-    Abs, Axes, Frame, FrameItem, FrameKind, GroupItem, Point, Sides, Size, Transform,
+    Abs, Axes, Frame, FrameItem, FrameKind, GroupItem, Point, Rect, Sides, Size,
+    Transform,
 };
 use typst_library::visualize::{Color, Geometry, Paint};
 use typst_utils::Scalar;
@@ -258,9 +260,176 @@ fn render_group(canvas: &mut sk::Pixmap, state: State, pos: Point, group: &Group
         mask = Some(&storage);
     }
 
+    // Translucent and blurred groups are rendered into a separate layer
+    // first, so that they are composited as a whole. The layer only covers the
+    // part of the canvas that the group's contents (and their blur) can reach.
+    if !group.opacity.is_one() || group.blur > Abs::zero() {
+        let sigma = blur_sigma(group.blur, state.transform);
+        let pad = (3.0 * sigma).ceil();
+        // Content just outside of the canvas may still blur into it.
+        let full = sk::IntRect::from_xywh(
+            -pad as i32,
+            -pad as i32,
+            canvas.width() + 2 * pad as u32,
+            canvas.height() + 2 * pad as u32,
+        );
+        let Some(rect) = layer_rect(&group.frame, state.transform, pad)
+            .zip(full)
+            .and_then(|(r, full)| r.intersect(&full))
+        else {
+            return;
+        };
+
+        let Some(mut layer) = sk::Pixmap::new(rect.width(), rect.height()) else {
+            return;
+        };
+
+        // Shift everything so that the layer's origin lies at the top-left
+        // corner of the bounds. The mask is applied when compositing since it
+        // covers the whole canvas.
+        let (dx, dy) = (-rect.x() as f32, -rect.y() as f32);
+        let layer_state = State {
+            transform: state.transform.post_translate(dx, dy),
+            container_transform: state.container_transform.post_translate(dx, dy),
+            mask: None,
+            ..state
+        };
+        render_frame(&mut layer, layer_state, &group.frame);
+        blur::blur(&mut layer, sigma);
+
+        let paint = sk::PixmapPaint {
+            opacity: group.opacity.get() as f32,
+            ..Default::default()
+        };
+        canvas.draw_pixmap(
+            rect.x(),
+            rect.y(),
+            layer.as_ref(),
+            &paint,
+            sk::Transform::identity(),
+            mask,
+        );
+        return;
+    }
+
     render_frame(canvas, state.with_mask(mask), &group.frame);
 }
 
+/// Rasterizes a frame with a Gaussian blur of the given standard deviation.
+///
+/// This is used by exporters without a native blur. Returns the blurred pixels
+/// along with the position of their top-left corner relative to the frame's
+/// origin. The pixmap extends beyond the frame's contents so that the blur can
+/// spill over their edges.
+pub fn render_blurred(
+    frame: &Frame,
+    radius: Abs,
+    pixel_per_pt: f32,
+) -> Option<(sk::Pixmap, Point)> {
+    let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt);
+    let sigma = blur_sigma(radius, ts);
+    let rect = layer_rect(frame, ts, (3.0 * sigma).ceil())?;
+    let mut pixmap = sk::Pixmap::new(rect.width(), rect.height())?;
+
+    let ts = ts.post_translate(-rect.x() as f32, -rect.y() as f32);
+    render_frame(&mut pixmap, State::new(frame.size(), ts, pixel_per_pt), frame);
+    blur::blur(&mut pixmap, sigma);
+
+    let origin = Point::new(
+        Abs::pt(f64::from(rect.x()) / f64::from(pixel_per_pt)),
+        Abs::pt(f64::from(rect.y()) / f64::from(pixel_per_pt)),
+    );
+    Some((pixmap, origin))
+}
+
+/// Converts a blur radius into a standard deviation in device pixels.
+fn blur_sigma(radius: Abs, ts: sk::Transform) -> f32 {
+    radius.to_f32() * (ts.sx * ts.sy - ts.kx * ts.ky).abs().sqrt()
+}
+
+/// Computes the pixel-aligned area that a layer for the frame's contents
+/// needs to cover, extending the contents' bounds by `pad` pixels.
+fn layer_rect(frame: &Frame, ts: sk::Transform, pad: f32) -> Option<sk::IntRect> {
+    // Antialiasing may touch one pixel beyond the bounds.
+    let pad = pad + 1.0;
+    let r = bounds(frame, ts)?;
+    sk::Rect::from_ltrb(r.left() - pad, r.top() - pad, r.right() + pad, r.bottom() + pad)?
+        .round_out()
+}
+
+/// Computes a conservative bounding box of a frame's contents in device
+/// coordinates.
+fn bounds(frame: &Frame, ts: sk::Transform) -> Option<sk::Rect> {
+    let mut bounds: Option<sk::Rect> = None;
+    for (pos, item) in frame.items() {
+        let ts = ts.pre_translate(pos.x.to_f32(), pos.y.to_f32());
+        let rect = match item {
+            FrameItem::Group(group) => {
+                let ts = ts.pre_concat(to_sk_transform(&group.transform));
+                match &group.clip {
+                    Some(clip) => {
+                        to_sk_rect(clip.bbox(None)).and_then(|r| r.transform(ts))
+                    }
+                    None => bounds(&group.frame, ts).and_then(|r| {
+                        // A blur spills over the contents' edges.
+                        let pad = 3.0 * blur_sigma(group.blur, ts);
+                        sk::Rect::from_ltrb(
+                            r.left() - pad,
+                            r.top() - pad,
+                            r.right() + pad,
+                            r.bottom() + pad,
+                        )
+                    }),
+                }
+            }
+            FrameItem::Text(text) => {
+                // Not all glyphs have outlines with a bounding box (e.g. bitmap
+                // emoji), so we include the text's em box as well.
+                let bbox = text.bbox();
+                let pad = text.stroke.as_ref().map_or(Abs::zero(), |s| s.thickness);
+                let rect = Rect::new(
+                    Point::new(bbox.min.x.min(Abs::zero()), bbox.min.y.min(-text.size))
+                        - Point::splat(pad),
+                    Point::new(bbox.max.x.max(text.width()), bbox.max.y.max(text.size))
+                        + Point::splat(pad),
+                );
+                to_sk_rect(rect).and_then(|r| r.transform(ts))
+            }
+            FrameItem::Shape(shape, _) => {
+                to_sk_rect(shape.bbox(true)).and_then(|r| r.transform(ts))
+            }
+            FrameItem::Image(_, size, _) => {
+                to_sk_rect(Rect::from_pos_size(Point::zero(), *size))
+                    .and_then(|r| r.transform(ts))
+            }
+            FrameItem::Link(_, _) | FrameItem::Tag(_) => None,
+        };
+
+        if let Some(rect) = rect {
+            bounds = Some(match bounds {
+                Some(prev) => sk::Rect::from_ltrb(
+                    prev.left().min(rect.left()),
+                    prev.top().min(rect.top()),
+                    prev.right().max(rect.right()),
+                    prev.bottom().max(rect.bottom()),
+                )?,
+                None => rect,
+            });
+        }
+    }
+    bounds
+}
+
+/// Converts a rectangle into a `tiny-skia` rectangle.
+fn to_sk_rect(rect: Rect) -> Option<sk::Rect> {
+    sk::Rect::from_ltrb(
+        rect.min.x.to_f32(),
+        rect.min.y.to_f32(),
+        rect.max.x.to_f32(),
+        rect.max.y.to_f32(),
+    )
+}
+
 fn to_sk_transform(transform: &Transform) -> sk::Transform {
     let Transform { sx, ky, kx, sy, tx, ty } = *transform;
     sk::Transform::from_row(
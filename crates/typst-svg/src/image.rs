@@ -102,17 +102,10 @@ impl WebImage {
     #[comemo::memoize]
     pub fn new(image: &Image) -> WebImage {
         let (format, data) = match image.kind() {
-            ImageKind::Raster(raster) => match raster.format() {
-                RasterFormat::Exchange(format) => (
-                    match format {
-                        ExchangeFormat::Png => WebImageFormat::Png,
-                        ExchangeFormat::Jpg => WebImageFormat::Jpg,
-                        ExchangeFormat::Gif => WebImageFormat::Gif,
-                        ExchangeFormat::Webp => WebImageFormat::Webp,
-                    },
-                    raster.data().clone(),
-                ),
-                RasterFormat::Pixel(_) => (WebImageFormat::Png, {
+            ImageKind::Raster(raster) => match web_raster_format(raster.format()) {
+                Some(format) => (format, raster.data().clone()),
+                // Formats that browsers can't display are converted to PNG.
+                None => (WebImageFormat::Png, {
                     let mut buf = vec![];
                     let mut encoder = PngEncoder::new(&mut buf);
                     if let Some(icc_profile) = raster.icc() {
@@ -142,6 +135,17 @@ impl WebImage {
     }
 }
 
+/// The web format of raster image data, if browsers can display it as is.
+fn web_raster_format(format: RasterFormat) -> Option<WebImageFormat> {
+    match format {
+        RasterFormat::Exchange(ExchangeFormat::Png) => Some(WebImageFormat::Png),
+        RasterFormat::Exchange(ExchangeFormat::Jpg) => Some(WebImageFormat::Jpg),
+        RasterFormat::Exchange(ExchangeFormat::Gif) => Some(WebImageFormat::Gif),
+        RasterFormat::Exchange(ExchangeFormat::Webp) => Some(WebImageFormat::Webp),
+        RasterFormat::Exchange(ExchangeFormat::Tiff) | RasterFormat::Pixel(_) => None,
+    }
+}
+
 // Keep this in sync with `typst-png`!
 fn pdf_to_svg(pdf: &PdfImage) -> String {
     let select_standard_font = move |font: StandardFont| -> Option<(FontData, u32)> {
@@ -225,6 +225,10 @@ struct SVGRenderer<'a> {
     /// different transforms. Therefore this allows us to reuse the same gradient
     /// multiple times.
     tiling_refs: Deduplicator<TilingRef>,
+    /// Blur filters. They are deduplicated by their radius and the size of the
+    /// region they cover, which is the blurred frame plus room for the blur to
+    /// spill over its edges.
+    filters: Deduplicator<(Abs, Size)>,
 }
 
 /// Contextual information for rendering.
@@ -282,6 +286,7 @@ impl<'a> SVGRenderer<'a> {
             conic_subgradients: Deduplicator::new('s'),
             tilings: Deduplicator::new('t'),
             tiling_refs: Deduplicator::new('p'),
+            filters: Deduplicator::new('b'),
         }
     }
 
@@ -331,19 +336,25 @@ impl<'a> SVGRenderer<'a> {
     fn render_group(&mut self, svg: &mut SvgElem, state: &State, group: &GroupItem) {
         let mut svg = svg.lazy_elem("g");
 
+        let blurred = group.blur > Abs::zero();
         let state = match group.frame.kind() {
-            FrameKind::Soft => state.pre_concat(group.transform),
-            FrameKind::Hard => {
-                // Always generate a group for hard frames.
+            FrameKind::Soft if !blurred => state.pre_concat(group.transform),
+            kind => {
+                // Always generate a group for hard frames. Blurred groups also
+                // get their own coordinate system, so that the blur's radius
+                // and region can be expressed in it.
                 svg.init();
 
                 let transform = state.transform.pre_concat(group.transform);
                 if !transform.is_identity() {
                     svg.init().attr("transform", SvgTransform(transform));
                 }
-                state
-                    .with_transform(Transform::identity())
-                    .with_size(group.frame.size())
+
+                let state = state.with_transform(Transform::identity());
+                match kind {
+                    FrameKind::Soft => state,
+                    FrameKind::Hard => state.with_size(group.frame.size()),
+                }
             }
         };
 
@@ -359,6 +370,16 @@ impl<'a> SVGRenderer<'a> {
             svg.init().attr("clip-path", SvgUrl(id));
         }
 
+        if !group.opacity.is_one() {
+            svg.init().attr("opacity", group.opacity.get());
+        }
+
+        if blurred {
+            let key = (group.blur, group.frame.size());
+            let id = self.filters.insert_with(key, || key);
+            svg.init().attr("filter", SvgUrl(id));
+        }
+
         self.render_frame(svg.lazy(), &state, &group.frame);
     }
 
@@ -414,6 +435,7 @@ impl<'a> SVGRenderer<'a> {
     fn finalize(mut self, mut svg: SvgElem) {
         self.write_glyph_defs(&mut svg);
         self.write_clip_path_defs(&mut svg);
+        self.write_filter_defs(&mut svg);
         self.write_gradients(&mut svg);
         self.write_gradient_refs(&mut svg);
         self.write_subgradients(&mut svg);
@@ -434,6 +456,29 @@ impl<'a> SVGRenderer<'a> {
             });
         }
     }
+
+    /// Build the blur filter definitions.
+    fn write_filter_defs(&self, svg: &mut SvgElem) {
+        if self.filters.is_empty() {
+            return;
+        }
+
+        let mut defs = svg.elem("defs");
+        for (id, &(radius, size)) in self.filters.iter() {
+            // A Gaussian blur is negligible beyond three standard deviations.
+            let pad = 3.0 * radius.to_pt();
+            defs.elem("filter")
+                .attr("id", id)
+                .attr("filterUnits", "userSpaceOnUse")
+                .attr("x", -pad)
+                .attr("y", -pad)
+                .attr("width", size.x.to_pt() + 2.0 * pad)
+                .attr("height", size.y.to_pt() + 2.0 * pad)
+                .with(|svg| {
+                    svg.elem("feGaussianBlur").attr("stdDeviation", radius.to_pt());
+                });
+        }
+    }
 }
 
 /// Write the default SVG header, including a `typst-doc` class, the
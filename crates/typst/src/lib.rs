@@ -323,3 +323,103 @@ static ROUTINES: LazyLock<Routines> = LazyLock::new(|| Routines {
     html_mathml_body: typst_html::html_mathml_body,
     html_span_filled: typst_html::html_span_filled,
 });
+
+#[cfg(test)]
+mod tests {
+    use typst_layout::PagedDocument;
+    use typst_library::diag::{FileError, FileResult};
+    use typst_library::engine::DepthLimits;
+    use typst_library::foundations::{Bytes, Datetime, Duration};
+    use typst_library::text::{Font, FontBook};
+    use typst_syntax::{RootedPath, Source, VirtualPath, VirtualRoot};
+    use typst_utils::LazyHash;
+
+    use super::*;
+
+    /// A world with a single source file and a library with custom limits.
+    struct DepthLimitsWorld {
+        library: LazyHash<Library>,
+        book: LazyHash<FontBook>,
+        main: Source,
+    }
+
+    impl DepthLimitsWorld {
+        fn new(text: &str, limits: DepthLimits) -> Self {
+            let id = RootedPath::new(
+                VirtualRoot::Project,
+                VirtualPath::new("main.typ").unwrap(),
+            )
+            .intern();
+            Self {
+                library: LazyHash::new(
+                    Library::builder().with_depth_limits(limits).build(),
+                ),
+                book: LazyHash::new(FontBook::new()),
+                main: Source::new(id, text.into()),
+            }
+        }
+    }
+
+    impl World for DepthLimitsWorld {
+        fn library(&self) -> &LazyHash<Library> {
+            &self.library
+        }
+
+        fn book(&self) -> &LazyHash<FontBook> {
+            &self.book
+        }
+
+        fn main(&self) -> FileId {
+            self.main.id()
+        }
+
+        fn source(&self, id: FileId) -> FileResult<Source> {
+            if id == self.main.id() {
+                Ok(self.main.clone())
+            } else {
+                Err(FileError::NotFound(id.vpath().get_without_slash().into()))
+            }
+        }
+
+        fn file(&self, id: FileId) -> FileResult<Bytes> {
+            Err(FileError::NotFound(id.vpath().get_without_slash().into()))
+        }
+
+        fn font(&self, _: usize) -> Option<Font> {
+            None
+        }
+
+        fn today(&self, _: Option<Duration>) -> Option<Datetime> {
+            None
+        }
+    }
+
+    /// Compiles the text and returns the message of the first error, if any.
+    fn first_error(text: &str, limits: DepthLimits) -> Option<EcoString> {
+        let world = DepthLimitsWorld::new(text, limits);
+        let errors = compile::<PagedDocument>(&world).output.err()?;
+        Some(errors[0].message.clone())
+    }
+
+    #[test]
+    fn test_lowered_call_depth() {
+        let text = "#let rec(n) = if n > 0 { rec(n - 1) }\n#rec(10)";
+        assert_eq!(first_error(text, DepthLimits::default()), None);
+        assert_eq!(
+            first_error(text, DepthLimits::default().with_call_depth(5)).as_deref(),
+            Some("maximum function call depth exceeded"),
+        );
+    }
+
+    #[test]
+    fn test_lowered_show_rule_depth() {
+        let text = "#show emph: it => it.body\n\
+                    #let nest(n) = if n > 0 { emph(nest(n - 1)) } else []\n\
+                    #nest(10)";
+        assert_eq!(first_error(text, DepthLimits::default()), None);
+        assert_eq!(
+            first_error(text, DepthLimits::default().with_show_rule_depth(5)).as_deref(),
+            Some("maximum show rule depth exceeded"),
+        );
+    }
+}
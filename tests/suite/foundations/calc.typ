@@ -8,6 +8,38 @@
 #test(type(calc.round(523, digits: -2)), int)
 #test(type(calc.round(decimal("314.15"), digits: -2)), decimal)
 
+--- calc-floor-ceil-trunc-digits eval ---
+#test(calc.floor(3.1415, digits: 2), 3.14)
+#test(calc.ceil(3.1415, digits: 2), 3.15)
+#test(calc.trunc(3.1415, digits: 3), 3.141)
+#test(calc.floor(-3.1415, digits: 2), -3.15)
+#test(calc.ceil(-3.1415, digits: 2), -3.14)
+#test(calc.trunc(-3.1415, digits: 2), -3.14)
+#test(calc.floor(0.29, digits: 2), 0.29)
+#test(calc.ceil(0.29, digits: 2), 0.29)
+#test(calc.floor(0.57, digits: 2), 0.57)
+#test(calc.floor(0.2999999999995, digits: 2), 0.29)
+#test(calc.ceil(0.3000000000005, digits: 2), 0.31)
+#test(calc.floor(1299.5, digits: -2), 1200.0)
+#test(calc.floor(1299, digits: -2), 1200)
+#test(calc.floor(-1201, digits: -2), -1300)
+#test(calc.ceil(1201, digits: -2), 1300)
+#test(calc.ceil(-1299, digits: -2), -1200)
+#test(calc.trunc(-1299, digits: -2), -1200)
+#test(calc.floor(7, digits: 2), 7)
+#test(calc.floor(decimal("-3.1415"), digits: 2), decimal("-3.15"))
+#test(calc.ceil(decimal("3.1415"), digits: 2), decimal("3.15"))
+#test(calc.trunc(decimal("3.1415"), digits: 2), decimal("3.14"))
+#test(calc.ceil(decimal("1201"), digits: -2), decimal("1300"))
+#test(type(calc.floor(3.1415)), int)
+#test(type(calc.floor(3.1415, digits: 2)), float)
+#test(type(calc.floor(3.1415, digits: 0)), float)
+#test(type(calc.ceil(decimal("3.1415"), digits: 2)), decimal)
+
+--- calc-floor-digits-too-large eval ---
+// Error: 2-38 the result is too large
+#calc.floor(int.min + 1, digits: -18)
+
 --- calc-round-large-inputs eval ---
 #test(calc.round(31114, digits: 4000000000), 31114)
 #test(calc.round(int.max, digits: 12), int.max)
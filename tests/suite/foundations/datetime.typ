@@ -77,6 +77,22 @@
 #test(datetime(day: 1, month: 3, year: 2001).ordinal(), 31 + 28 + 1);
 #test(datetime(day: 31, month: 12, year: 2001).ordinal(), 365);
 
+--- datetime-from-iso eval ---
+#test(datetime.from-iso("2024-03-12"), datetime(year: 2024, month: 3, day: 12))
+#test(datetime.from-iso("14:30:05"), datetime(hour: 14, minute: 30, second: 5))
+#test(
+  datetime.from-iso("2024-03-12T14:30:05"),
+  datetime(year: 2024, month: 3, day: 12, hour: 14, minute: 30, second: 5),
+)
+#test(
+  datetime.from-iso("2024-03-12T14:30:05Z"),
+  datetime.from-iso("2024-03-12T14:30:05"),
+)
+
+--- datetime-from-iso-invalid eval ---
+// Error: 2-33 invalid ISO 8601 datetime: "2024-13-01"
+#datetime.from-iso("2024-13-01")
+
 --- datetime-incomplete-time-1 eval ---
 // Error: 2-34 time is incomplete
 // Hint: 2-34 add the `hour` argument to get a valid time
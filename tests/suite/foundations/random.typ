@@ -0,0 +1,48 @@
+// Test random number generation.
+
+--- random-deterministic eval ---
+// The same seed produces the same sequence.
+#let (a1, x1) = random(seed: 42).int(0, 1000)
+#let (a2, x2) = random(seed: 42).int(0, 1000)
+#test(x1, x2)
+#test(a1, a2)
+#test(a1.float(), a2.float())
+#test(random(seed: 1) == random(seed: 2), false)
+
+--- random-int eval ---
+#let rng = random(seed: 7)
+#for _ in range(100) {
+  let (next, value) = rng.int(-3, 4)
+  test(type(value), int)
+  assert(value >= -3 and value < 4)
+  rng = next
+}
+
+--- random-float eval ---
+#let rng = random()
+#for _ in range(100) {
+  let (next, value) = rng.float()
+  assert(value >= 0.0 and value < 1.0)
+  rng = next
+}
+
+--- random-pick-shuffle eval ---
+#let items = ("a", "b", "c", "d", "e")
+#let (rng, item) = random(seed: 3).pick(items)
+#test(item in items, true)
+#let (rng, shuffled) = rng.shuffle(items)
+#test(shuffled.len(), items.len())
+#test(shuffled.sorted(), items)
+#test(random().shuffle(()).at(1), ())
+
+--- random-repr eval ---
+#test(repr(random()), "random(..)")
+#test(type(random()), random)
+
+--- random-int-empty eval ---
+// Error: 2-20 range must not be empty (low: 5, high: 5)
+#random().int(5, 5)
+
+--- random-pick-empty eval ---
+// Error: 2-19 cannot pick from an empty array
+#random().pick(())
@@ -61,6 +61,50 @@
 // Error: 17-19 base must be between 2 and 36
 #str(123, base: 99)
 
+--- str-constructor-format eval ---
+// Test number formatting options.
+#test(str(3.14159, precision: 2), "3.14")
+#test(str(2.7, precision: 0), "3")
+#test(str(5, precision: 2), "5.00")
+#test(str(-0.001, precision: 2), "0.00")
+#test(str(7, digits: 3), "007")
+#test(str(-42, digits: 4), "−0042")
+#test(str(255, base: 16, digits: 4), "00ff")
+#test(str(1000000, thousands: ","), "1,000,000")
+#test(str(-1234.5, thousands: ","), "−1,234.5")
+#test(str(123, thousands: ","), "123")
+#test(str(1234567.891, precision: 2, thousands: ","), "1,234,567.89")
+#test(str(1234.5, decimal: ",", thousands: "."), "1.234,5")
+#test(str(6.022e23, scientific: true), "6.022e23")
+#test(str(12345.678, precision: 2, scientific: true), "1.23e4")
+#test(str(0.00123, precision: 1, scientific: true), "1.2e−3")
+#test(str(1500, scientific: true), "1.5e3")
+#test(str(float.inf, precision: 2), "∞")
+#test(str(0.1, precision: 17), "0.10000000000000001")
+#test(str(0.1, precision: 20), "0.10000000000000000555")
+#test(str(5e-324, precision: 340).len(), 342)
+#test(str(-1, base: 2, digits: 64).len(), 64 + "−".len())
+
+--- str-constructor-format-string eval ---
+// Error: 2-24 number formatting is only supported for integers and floats
+#str("a", precision: 2)
+
+--- str-constructor-format-scientific-base eval ---
+// Error: 16-18 scientific notation is only supported in base 10
+#str(10, base: 16, scientific: true)
+
+--- str-constructor-format-precision-too-large eval ---
+// Error: 22-25 precision must be at most 340
+#str(1.5, precision: 341)
+
+--- str-constructor-format-precision-too-large-scientific eval ---
+// Error: 22-24 precision must be at most 17 in scientific notation
+#str(1.5, precision: 18, scientific: true)
+
+--- str-constructor-format-digits-too-large eval ---
+// Error: 17-29 digits must be at most 64
+#str(1, digits: 100000000000)
+
 --- str-constructor-unary eval ---
 // Error: 17-18 base must be between 2 and 36
 // Hint: 17-18 generate a unary representation with `"1" * 999`
@@ -305,6 +305,88 @@ C
 // Hint: 2-20 you can enable floating placement with `place(float: true, ..)`
 #place(auto)[Hello]
 
+--- place-wrap-shortens-lines paged empty ---
+// Test that lines beside wrapped content are shortened.
+#context {
+  let body = lorem(30)
+  let plain = measure(block(width: 100pt, body))
+  let wrapped = measure(block(width: 100pt)[
+    #place(left, wrap: true, rect(width: 40pt, height: 30pt))
+    #body
+  ])
+  test(wrapped.width, plain.width)
+  assert(wrapped.height > plain.height)
+}
+
+--- place-wrap-region-break paged ---
+// Test that lines which are pushed to a later page than the wrapped content
+// are not shortened.
+#set page(width: 100pt, height: 50pt, margin: 10pt)
+#place(left, wrap: true, rect(width: 30pt, height: 60pt))
+A #linebreak() B #linebreak() C #linebreak() D #linebreak()#metadata(none) <c>E
+
+#context test(locate(<c>).position().x, 10pt)
+
+--- place-wrap-float paged ---
+// Error: 2-44 wrapping is not available for floating placement
+#place(top, float: true, wrap: true)[Hello]
+
+--- place-wrap-center paged ---
+// Error: 2-31 wrapped content must be aligned to the start or end
+// Hint: 2-31 try `place(left, wrap: true, ..)` or `place(right, wrap: true, ..)`
+#place(center, wrap: true)[Hi]
+
+--- place-page-scope paged empty ---
+// Test that page-scoped content is positioned relative to the page, even when
+// nested in other containers.
+#place(hide(block(inset: 10pt)[
+  Before
+  #place(top + left, scope: "page", dx: 3pt, dy: 4pt)[#metadata(none) <a>]
+  #block[#place(bottom + right, scope: "page", dx: -5pt)[#metadata(none) <b>]]
+  After
+]))
+
+#context {
+  test(locate(<a>).position(), (page: 1, x: 3pt, y: 4pt))
+  test(locate(<b>).position(), (page: 1, x: 115pt, y: 20pt))
+}
+
+--- place-page-scope-styles paged empty ---
+// Test that page-scoped content is laid out with the styles where it is placed.
+#place(hide[
+  #set text(5pt)
+  #place(top + left, scope: "page", dx: 2em)[
+    #metadata(none) <a>#context [#metadata(text.size) <size>]
+  ]
+])
+
+#context {
+  test(locate(<a>).position().x, 10pt)
+  test(query(<size>).first().value, 5pt)
+}
+
+--- place-page-scope-float paged ---
+// Error: 2-47 page-scoped placement cannot be floating
+#place(top, scope: "page", float: true)[Hello]
+
+--- place-page-scope-header paged empty ---
+// Warning: 19-42 page-scoped placement was ignored
+// Hint: 19-42 page-scoped placement is only supported in the body of a page
+#set page(header: place(scope: "page")[A])
+
+--- place-page-scope-measure paged empty ---
+// Warning: 23-61 page-scoped placement was ignored
+// Hint: 23-61 page-scoped content does not contribute to the measured size
+#context test(measure(place(scope: "page", rect(width: 5pt))).width, 0pt)
+
+--- place-page-scope-figure paged ---
+// Error: 2-42 page-scoped placement is not available for floating figures
+#figure(placement: top, scope: "page")[A]
+
+--- place-page-scope-figure-non-floating paged empty ---
+// The scope of a non-floating figure has no effect.
+#place(hide(figure(scope: "page")[A]))
+
 --- place-float-center-horizon paged ---
 // Error: 2-45 vertical floating placement must be `auto`, `top`, or `bottom`
 #place(center + horizon, float: true)[Hello]
@@ -214,6 +214,28 @@
   [Hello there, my friends! Hi! What is going on right now?],
 )
 
+--- grid-column-bounds paged empty ---
+// Test minimum and maximum widths of auto columns.
+#context {
+  let narrow = grid(columns: (auto, auto), column-min: 20pt)[A][B]
+  test(measure(narrow).width, 40pt)
+
+  let wide = grid(columns: (auto,), column-max: 50pt, lorem(20))
+  test(measure(wide).width, 50pt)
+
+  let mixed = grid(
+    columns: (auto, 10pt, auto),
+    column-gutter: 1pt,
+    column-min: (30pt, auto, 20pt),
+    [A], [B], [C],
+  )
+  test(measure(mixed).width, 62pt)
+}
+
+--- grid-column-bounds-invalid eval ---
+// Error: 31-34 expected relative length, auto, or array, found string
+#grid(columns: 1, column-min: "a")
+
 --- issue-grid-base-auto-row paged ---
 // Test that grid base for auto rows makes sense.
 #set page(height: 150pt)
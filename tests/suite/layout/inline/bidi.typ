@@ -96,3 +96,18 @@ Lריווח #h(1cm) R
 --- issue-5276-shaping-consecutive-ltr-with-lang paged ---
 #let a = text(lang: "ar")[\u{645}]
 #a#a
+
+--- bidi-isolate paged ---
+// Test that isolated content doesn't affect the surrounding ordering.
+#set text(lang: "he", font: ("Libertinus Serif", "Noto Serif Hebrew"))
+המשתמש #isolate[user123!] הגיב. \
+המשתמש user123! הגיב. \
+#isolate(dir: rtl)[טֶקסט 1 - 2] Text \
+#isolate(dir: ltr)[1 - 2] טֶקסט
+
+--- bidi-isolate-vertical eval ---
+// Error: 15-18 text direction must be horizontal
+#isolate(dir: ttb)[A]
+
+--- bidi-isolate-html html ---
+#isolate[A] #isolate(dir: rtl)[B]
@@ -25,6 +25,27 @@ Welcome to wonde#text(hyphenate: false)[rf]ul experiences. \
 Welcome to wonderful experiences. \
 Welcome to wo#text(hyphenate: true)[nd]erful experiences. \
 
+--- hyphenate-exceptions paged empty ---
+// Test custom hyphenation exceptions.
+#set text(hyphenate: true)
+#let height(body) = measure(block(width: 1pt, body)).height
+#context {
+  let single = height[x]
+  assert(height[typesetting] > single)
+  test(height(text(hyphenation-exceptions: ("TypeSetting",))[typesetting]), single)
+  test(
+    height(text(hyphenation-exceptions: ("type-setting",))[typesetting]),
+    height(text(hyphenate: false)[type \ setting]),
+  )
+
+  // Exceptions also apply to languages without patterns.
+  test(height(text(lang: "tok")[typesetting]), single)
+  assert(
+    height(text(lang: "tok", hyphenation-exceptions: ("type-set-ting",))[typesetting])
+      > single
+  )
+}
+
 --- hyphenate-between-shape-runs paged ---
 // Hyphenate between shape runs.
 #set page(width: 80pt)
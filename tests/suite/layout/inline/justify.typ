@@ -259,6 +259,17 @@ int main() {
 #set par(justify: true)
 #block(width: 1cm, fill: aqua, lorem(2))
 
+--- justify-kashida paged ---
+// Test justifying Arabic text with kashidas.
+#set page(width: 180pt)
+#set text(lang: "ar", font: "Noto Sans Arabic")
+#set par(justify: true)
+#let body = [هذا نص عربي لاختبار ضبط المسافات بين الكلمات في فقرة قصيرة.]
+#body
+
+#set text(kashida: true)
+#body
+
 --- issue-2419-justify-hanging-indent paged ---
 // Test that combination of justification and hanging indent doesn't result in
 // an underfull first line.
@@ -0,0 +1,25 @@
+// Test the `overlay` function.
+
+--- overlay-size paged empty ---
+// The overlay is as large as its largest child.
+#context test(
+  measure(overlay(
+    box(width: 30pt, height: 10pt),
+    box(width: 10pt, height: 20pt),
+  )),
+  (width: 30pt, height: 20pt),
+)
+
+--- overlay-align paged empty ---
+#place(hide(overlay(
+  box(width: 40pt, height: 40pt),
+  align(bottom + right, box(width: 10pt, height: 10pt)[#metadata(none) <br>]),
+  align(center + horizon, box(width: 20pt, height: 20pt)[#metadata(none) <c>]),
+  [#metadata(none) <tl>],
+)))
+
+#context {
+  test(locate(<tl>).position().x, 10pt)
+  test(locate(<br>).position().x, 40pt)
+  test(locate(<c>).position().x, 20pt)
+}
@@ -36,3 +36,14 @@ Hi #box(pad(left: 10pt)[A]) there
 --- issue-5160-unbreakable-pad paged ---
 #set block(breakable: false)
 #block(width: 100%, pad(x: 20pt, align(right)[A]))
+
+--- pad-negative paged empty ---
+// Negative padding lets content extend beyond the padding element.
+#place(hide(block(inset: 10pt)[
+  #pad(x: -10pt)[#metadata(none) <full>]
+]))
+#context test(locate(<full>).position().x, 10pt)
+
+--- pad-negative-larger-than-content paged empty ---
+// Negative padding larger than the content must not yield a negative size.
+#context test(measure(pad(-10pt)[]), (width: 0pt, height: 0pt))
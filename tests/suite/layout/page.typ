@@ -444,6 +444,14 @@ text
 #pagebreak()
 #rect(width: 100%)
 
+--- page-crop-marks paged ---
+// Printer's marks are placed outside of the page and its bleed.
+#set page(width: 3cm, height: 3cm, bleed: 3mm, crop-marks: true)
+#context {
+  test(page.crop-marks, true)
+  test(page.width, 3cm)
+}
+
 --- page-bleed-outer-auto eval ---
 // Ensure that the auto support from `margin` does not affect `bleed`.
 // Error: 18-22 expected relative length or dictionary, found auto
@@ -89,3 +89,13 @@ World! 🌍
   // Error: 3-40 stack spacing is infinite
   stack(spacing: infinite-length)[A][B]
 })
+
+--- stack-baseline paged empty ---
+#place(hide(stack(
+  dir: ltr,
+  baseline: true,
+  text(size: 20pt)[A#metadata(none) <big>],
+  [b#metadata(none) <small>],
+)))
+
+#context test(locate(<big>).position().y, locate(<small>).position().y)
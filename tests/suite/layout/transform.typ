@@ -178,6 +178,30 @@ Hello #skewed[World]!\
 #set skew(reflow: true)
 Hello #skewed[World]!
 
+--- transform-matrix-reflow paged empty ---
+// Test that a reflowed matrix transformation affects the bounding box.
+#let body = box(width: 10pt, height: 10pt)
+#context {
+  let plain = measure(transform(matrix: (2, 0, 0, 3, 5pt, 5pt), body))
+  test((plain.width, plain.height), (10pt, 10pt))
+  let reflowed = measure(transform(matrix: (2, 0, 0, 3, 5pt, 5pt), reflow: true, body))
+  test((reflowed.width, reflowed.height), (20pt, 30pt))
+  let sheared = measure(transform(matrix: (1, 0, 1, 1, 0pt, 0pt), reflow: true, body))
+  test((sheared.width, sheared.height), (20pt, 10pt))
+}
+
+--- transform-matrix-invalid eval ---
+// Error: 20-30 expected 6 matrix entries, found 3
+#transform(matrix: (1, 0, 1))[A]
+
+--- transform-matrix-not-finite eval ---
+// Error: 20-50 matrix entries must be finite
+#transform(matrix: (1, 0, 0, float.nan, 0pt, 0pt))[A]
+
+--- transform-matrix-infinite-offset eval ---
+// Error: 20-54 matrix entries must be finite
+#transform(matrix: (1, 0, 0, 1, float.inf * 1pt, 0pt))[A]
+
 --- transform-combinations paged ---
 #block[
   #rect(width: 10pt, height: 10pt)
@@ -0,0 +1,58 @@
+--- endnote-basic paged ---
+#set page(height: auto)
+Typst#endnote[A typesetting system.] is written
+in Rust.#endnote[A systems programming language.]
+
+#endnotes()
+
+--- endnote-per-listing paged ---
+// Each listing only displays the notes since the previous listing.
+#set page(height: auto)
+= Introduction
+Start.#endnote[A] #endnote[B]
+
+#endnotes()
+
+= Conclusion
+End.#endnote[C]
+
+#endnotes()
+
+--- endnote-numbering paged ---
+#set page(height: auto)
+#set endnote(numbering: "i")
+One#endnote[First] two#endnote[Second]
+
+#endnotes()
+
+--- endnote-entry-styling paged ---
+#set page(height: auto)
+#show endnote.entry: set text(blue)
+Some text.#endnote[A blue note.]
+
+#endnotes()
+
+--- endnote-html html ---
+Typst#endnote[A typesetting system.]
+
+#endnotes()
+
+--- endnote-per-listing-entries paged empty ---
+// Each note has exactly one entry, in the first listing after it.
+#place(hide[
+  A#endnote[a] B#endnote[b]
+  #endnotes() <first>
+  C#endnote[c]
+  #endnotes() <second>
+])
+
+#context {
+  let bodies(sel) = query(sel).map(entry => entry.note.body)
+  test(bodies(selector(endnote.entry).after(<first>).before(<second>)), ([a], [b]))
+  test(bodies(selector(endnote.entry).after(<second>)), ([c],))
+}
+
+--- endnote-no-listing paged empty ---
+// Warning: 15-25 endnote is not displayed in any listing
+// Hint: 15-25 add an `endnotes()` listing after the note
+#place(hide[A#endnote[a]])
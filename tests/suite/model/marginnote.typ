@@ -0,0 +1,68 @@
+--- marginnote-position paged empty ---
+#set marginnote(width: 5pt, clearance: 5pt)
+#place(hide[
+  A #metadata(none) <anchor> #marginnote(hide[#metadata(none) <note> Note])
+])
+
+#context {
+  let anchor = locate(<anchor>).position()
+  let note = locate(<note>).position()
+  test(note.x, 115pt)
+  assert(calc.abs((note.y - anchor.y).pt()) < 0.01)
+}
+
+--- marginnote-side paged empty ---
+#set marginnote(width: 5pt, clearance: 2pt)
+#place(hide[
+  A #marginnote(side: left, hide[#metadata(none) <left> L])
+  #marginnote(side: right, hide[#metadata(none) <right> R])
+])
+
+#context {
+  test(locate(<left>).position().x, 3pt)
+  test(locate(<right>).position().x, 112pt)
+  test(locate(<left>).position().y, locate(<right>).position().y)
+}
+
+--- marginnote-collision paged empty ---
+#set marginnote(width: 5pt, gap: 5pt)
+#place(hide[
+  A #marginnote(hide[#metadata(none) <a> First \ note \ here])
+  #marginnote(hide[#metadata(none) <b> Second])
+])
+
+#context {
+  let a = locate(<a>).position()
+  let b = locate(<b>).position()
+  test(a.x, b.x)
+  assert(b.y > a.y + 25pt)
+}
+
+--- marginnote-columns paged empty ---
+// In multi-column layouts, the side is determined by the column.
+#set page(columns: 2)
+#set marginnote(width: 5pt, clearance: 2pt)
+#place(hide[A #marginnote(side: right, hide[#metadata(none) <first> A])])
+#colbreak()
+#place(hide[B #marginnote(side: left, hide[#metadata(none) <second> B])])
+
+#context {
+  test(locate(<first>).position().x, 3pt)
+  test(locate(<second>).position().x, 112pt)
+}
+
+--- marginnote-local-styles paged empty ---
+// Test that notes are laid out with the styles where they are called.
+#set marginnote(clearance: 0pt)
+#place(hide[
+  A #[
+    #set marginnote(side: left, width: 2em)
+    #set text(5pt)
+    #marginnote(hide[#metadata(none) <note> #context [#metadata(text.size) <size>]])
+  ]
+])
+
+#context {
+  test(locate(<note>).position().x, 0pt)
+  test(query(<size>).first().value, 5pt)
+}
@@ -217,6 +217,18 @@ A
 
 = A
 
+--- outline-heading-short paged ---
+// The short title replaces the full title in the outline only.
+#set page(width: 150pt)
+#outline()
+
+#heading(short: [Results])[Experimental results for the first series]
+
+--- outline-heading-short-query paged empty ---
+#show heading: none
+#heading(short: [S])[Long]
+#context test(query(heading).first().short, [S])
+
 --- outline-styled-text paged ---
 #outline(title: none)
 
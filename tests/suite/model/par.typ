@@ -320,6 +320,49 @@ It is the east, and Juliet is the sun.
 #set par(spacing: 10pt)
 #context test(par.spacing, 10pt)
 
+--- par-baseline-grid paged empty ---
+// The baseline grid is measured from the top of the page's content area.
+#set page(height: 20pt, margin: 0pt)
+#set par(baseline-grid: 15pt)
+#show: hide
+#v(3pt)
+A #metadata(none) <a> #text(size: 13pt)[C #metadata(none) <c>]
+
+#context {
+  let y = locate(<a>).position().y
+  test(y, locate(<c>).position().y)
+  assert(calc.abs(y.pt() - 15) < 0.001)
+}
+
+--- par-baseline-grid-block paged empty ---
+// Blocks snap with their first baseline, whether they are breakable or not.
+#set page(height: 20pt, margin: 0pt)
+#set par(baseline-grid: 6pt)
+#set text(4pt)
+#show: hide
+#v(1pt)
+#block(breakable: false)[A #metadata(none) <a>]
+#block(breakable: true)[B #metadata(none) <b>]
+
+#context {
+  assert(calc.abs(locate(<a>).position().y.pt() - 6) < 0.001)
+  assert(calc.abs(locate(<b>).position().y.pt() - 18) < 0.001)
+}
+
+--- par-baseline-grid-auto-height paged empty ---
+// The grid also applies on pages with automatic height.
+#set page(height: auto, margin: 0pt)
+#set par(baseline-grid: 10pt)
+#show: hide
+#v(3pt)
+A #metadata(none) <a>
+
+#context assert(calc.abs(locate(<a>).position().y.pt() - 20) < 0.001)
+
+--- par-baseline-grid-invalid eval ---
+// Error: 25-30 expected length or none, found string
+#set par(baseline-grid: "1pt")
+
 --- par-first-line-indent paged ---
 #set par(first-line-indent: 12pt, spacing: 5pt, leading: 5pt)
 #show heading: set text(size: 10pt)
@@ -472,6 +515,82 @@ Welcome \ here. Does this work well?
 لآن وقد أظلم الليل وبدأت النجوم
 تنضخ وجه الطبيعة التي أعْيَتْ من طول ما انبعثت في النهار
 
+--- par-tab-stops paged ---
+#set page(width: 150pt)
+#set par(tab-stops: (
+  40pt,
+  (pos: 90pt, align: center),
+  (pos: 130pt, align: right),
+))
+
+A #tab() B #tab() C #tab() D \
+Longer #tab() Text #tab() Mid #tab() End \
+#tab() Only #tab() #tab() last
+
+--- par-tab-stops-decimal paged ---
+#set page(width: 120pt)
+#set par(tab-stops: ((pos: 60pt, align: "decimal"),))
+
+Total #tab() 3.50 \
+Tax #tab() 12.125 \
+Tip #tab() 100
+
+--- par-tab-stops-leader paged ---
+#set page(width: 150pt)
+#set par(tab-stops: ((pos: 130pt, align: right, leader: [.]),))
+
+Introduction #tab() 1 \
+Methods and Materials #tab() 12
+
+--- par-tab-stops-measure paged empty ---
+#context {
+  set par(tab-stops: (50pt, 80pt))
+  let b = measure[B].width
+  test(measure[A #tab() B].width, 50pt + b)
+  test(measure[A #tab() B #tab() B].width, 80pt + b)
+}
+
+--- par-tab-stops-none-left paged empty ---
+// A tab without further tab stops has no effect.
+#context {
+  set par(tab-stops: (10pt,))
+  test(measure[Long text #tab() B].width, measure[Long textB].width)
+}
+
+--- par-tab-stops-hanging-indent paged empty ---
+// Tab stops are measured from the start of the line, not the indent.
+#context {
+  set par(hanging-indent: 20pt, tab-stops: (50pt,))
+  let b = measure[B].width
+  test(measure(width: 100pt)[A #tab() B \ #tab() B].width, 50pt + b)
+}
+
+--- par-tab-stops-rtl paged empty ---
+// Tab stops are measured from the right in right-to-left text.
+#place(hide(block(width: 100%)[
+  #set text(dir: rtl)
+  #set par(tab-stops: (30pt,))
+  A#tab()#metadata(none) <b>B
+]))
+
+#context test(locate(<b>).position().x, 80pt)
+
+--- par-tab-stops-wrap paged empty ---
+// Tab stops are measured from the edge of the paragraph, even if the line is
+// shortened by wrapped content.
+#place(hide(block(width: 100%)[
+  #set par(tab-stops: (50pt,))
+  #place(left, wrap: true, clearance: 0pt, rect(width: 20pt, height: 10pt))
+
+  A#tab()#metadata(none) <b>B
+]))
+
+#context test(locate(<b>).position().x, 60pt)
+
+--- par-tab-stops-missing-pos eval ---
+// Error: 21-37 dictionary does not contain key "pos"
+#set par(tab-stops: ((align: left),))
+
 --- par-trailing-whitespace paged ---
 // Ensure that trailing whitespace layouts as intended.
 #box(fill: aqua, " ")
@@ -38,6 +38,8 @@
 
 --- recursion-maximum-depth eval ---
 // Error: 15-21 maximum function call depth exceeded
+// Hint: 15-21 maybe a recursive function is missing a base case
+// Hint: 15-21 the limit is 80 nested calls
 #let rec(n) = rec(n) + 1
 #rec(1)
 
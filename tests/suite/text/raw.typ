@@ -668,6 +668,62 @@ print(x)
 print(y)
 ```
 
+--- raw-numbering paged ---
+#set raw(numbering: "1")
+
+```rust
+fn main() {
+    let x = 1;
+
+    println!("{x}");
+}
+```
+
+--- raw-numbering-inline paged ---
+// Line numbers are only displayed for raw blocks.
+#set raw(numbering: "1")
+Inline `code` is not numbered.
+
+--- raw-numbering-html html ---
+#set raw(numbering: "1")
+// Warning: 2-23 raw line numbering was ignored during HTML export
+// Hint: 2-23 line numbers are only displayed in paged export
+#raw("a", block: true)
+
+--- raw-highlighted-lines paged ---
+#set raw(highlighted: (2, 4))
+
+```py
+def f(x):
+    y = x * 2
+    z = y + 1
+    return z
+```
+
+--- raw-range paged ---
+#set raw(range: (2, 3), numbering: "(1)")
+
+```rust
+fn main() {
+    let x = 1;
+    println!("{x}");
+}
+```
+
+--- raw-range-lines paged empty ---
+#show raw: code => {
+  test(code.lines.len(), 2)
+  test(code.lines.at(0).number, 2)
+  test(code.lines.at(1).text, "c")
+  test(code.lines.at(1).count, 4)
+}
+
+#raw("a\nb\nc\nd", block: true, range: (2, 3))
+
+--- raw-range-invalid eval ---
+// Error: 18-24 line range must be ascending and start at 1 or later
+#raw("a", range: (3, 2))
+
 --- issue-3601-empty-raw paged ---
 // Test that empty raw block with `typ` language doesn't cause a crash.
 ```typ
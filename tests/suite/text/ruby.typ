@@ -0,0 +1,23 @@
+// Test ruby annotations.
+
+--- ruby paged ---
+#set text(lang: "ja", font: "Noto Serif CJK SC")
+#ruby[東京][とうきょう]に#ruby[行][い]きます。
+
+#ruby(position: bottom)[漢字][かんじ]と#ruby(overhang: false)[字][あざな]
+
+--- ruby-size paged empty ---
+// The ruby grows by the annotation and keeps the base's baseline.
+#set text(size: 10pt, top-edge: 1em, bottom-edge: 0pt)
+#context {
+  let base = measure[A]
+  let ruby = measure(ruby(gap: 2pt)[A][B])
+  test(ruby.height, base.height + 7pt)
+  test(
+    measure(ruby(overhang: false)[A][BBBBBBBB]).width,
+    measure(text(5pt)[BBBBBBBB]).width,
+  )
+}
+
+--- ruby-html html ---
+#ruby[漢字][かんじ]
@@ -4,13 +4,38 @@
 
 --- smallcaps-show-rule paged ---
 // There is no dedicated smallcaps font in typst-dev-assets, so we just use some
-// other font to test this show rule.
+// other font to test this show rule. As PT Sans has no small capitals, they
+// are synthesized.
 #show smallcaps: set text(font: "PT Sans")
+// Warning: 12-21 current font does not support small capitals
+// Hint: 12-21 they were synthesized from scaled uppercase letters
+// Hint: 12-21 use a font with small capitals for better results
 #smallcaps[Smallcaps]
 
 #show smallcaps: set text(fill: red)
+// Warning: 12-21 current font does not support small capitals
+// Hint: 12-21 they were synthesized from scaled uppercase letters
+// Hint: 12-21 use a font with small capitals for better results
 #smallcaps[Smallcaps]
 
 --- smallcaps-all paged html ---
 #smallcaps(all: false)[Test 012] \
 #smallcaps(all: true)[Test 012]
+
+--- smallcaps-synthesized paged ---
+// Small capitals are synthesized if the font doesn't support them.
+#set text(font: "DejaVu Sans Mono")
+// Warning: 12-21 current font does not support small capitals
+// Hint: 12-21 they were synthesized from scaled uppercase letters
+// Hint: 12-21 use a font with small capitals for better results
+#smallcaps[Smallcaps] \
+// Warning: 23-29 current font does not support small capitals
+// Hint: 23-29 they were synthesized from scaled uppercase letters
+// Hint: 23-29 use a font with small capitals for better results
+#smallcaps(all: true)[UNICEF]
+
+--- smallcaps-synthesized-uppercase paged empty ---
+// Uppercase letters are not turned into small capitals by default, so there is
+// nothing to synthesize.
+#set text(font: "DejaVu Sans Mono")
+#hide(smallcaps[UNICEF 2024])
@@ -0,0 +1,42 @@
+// Test arcs and pie sectors.
+
+--- arc paged ---
+#set page(width: 120pt, height: auto)
+#set arc(radius: 15pt)
+#stack(
+  dir: ltr,
+  spacing: 5pt,
+  arc(),
+  arc(start: 0deg, stop: 270deg, stroke: 2pt + blue),
+  arc(start: 90deg, stop: -90deg),
+  arc(radius: (20pt, 10pt), start: 180deg, stop: 360deg),
+)
+
+--- arc-pie paged ---
+#set page(width: auto, height: auto, margin: 5pt)
+#let slice(start, stop, color) = place(arc(
+  radius: 25pt,
+  start: start,
+  stop: stop,
+  pie: true,
+  fill: color,
+  stroke: white,
+))
+
+#box(width: 50pt, height: 50pt, {
+  slice(0deg, 120deg, red)
+  slice(120deg, 200deg, green)
+  slice(200deg, 360deg, blue)
+})
+
+--- arc-segment-fill paged ---
+#set page(width: auto, height: auto, margin: 5pt)
+#arc(radius: (30pt, 15pt), start: 200deg, stop: 340deg, fill: eastern)
+
+--- arc-radius-invalid eval ---
+// Error: 14-18 expected length or array, found string
+#arc(radius: "no")
+
+--- arc-radius-negative paged ---
+// Error: 2-27 cannot create arc with negative radius
+#arc(radius: (10pt, -5pt))
@@ -0,0 +1,24 @@
+// Test blurred content.
+
+--- blur paged ---
+#set page(width: 120pt, height: auto)
+#blur(1pt)[Blurry text.]
+#blur(3pt, rect(fill: blue, width: 100%, height: 10pt))
+
+--- blur-spill paged ---
+// The blur spills over the content's edges without affecting the layout.
+#set page(width: auto, height: auto, margin: 10pt)
+#blur(2pt, rect(width: 30pt, height: 20pt, fill: red))
+
+--- blur-translucent paged ---
+#set page(width: 100pt, height: 60pt)
+#place(center + horizon, opacity(50%, blur(2pt, text(24pt, red)[DRAFT])))
+This document is not final.
+
+--- blur-zero paged ---
+// A zero radius leaves the content unchanged.
+#blur(0pt)[Sharp text.]
+
+--- blur-negative paged ---
+// Error: 2-15 blur radius must not be negative
+#blur(-1pt)[A]
@@ -51,6 +51,50 @@ A paragraph followed by an image:
   image("/assets/images/monkey.svg", width: 100%, height: 100%, fit: "stretch"),
 )
 
+--- image-focus paged ---
+// Test that the focus determines the visible part of a covered image.
+#set page(height: 50pt, margin: 0pt)
+#set image(width: 100%, height: 100%)
+#grid(
+  columns: (1fr, 1fr, 1fr, 1fr),
+  rows: 100%,
+  gutter: 3pt,
+  image("/assets/images/tiger.jpg", focus: left),
+  image("/assets/images/tiger.jpg", focus: right),
+  image("/assets/images/tiger.jpg", fit: "contain", focus: top),
+  image("/assets/images/tiger.jpg", fit: "contain", focus: bottom),
+)
+
+--- image-crop paged ---
+#set page(height: 60pt, margin: 0pt)
+#set image(height: 100%)
+#image("/assets/images/tiger.jpg", crop: (left: 25%, right: 25%))
+#image("/assets/images/tiger.jpg", crop: (top: 40%, rest: 10%))
+
+--- image-crop-cover paged ---
+#set page(width: 60pt, height: 60pt, margin: 0pt)
+#image(
+  "/assets/images/tiger.jpg",
+  width: 100%,
+  height: 100%,
+  crop: (x: 30%),
+  focus: top,
+)
+
+--- image-crop-invalid paged ---
+// Error: 2-63 image crop must leave part of the image visible
+#image("/assets/images/tiger.jpg", crop: (x: 50%, right: 60%))
+
+--- image-filter paged ---
+#set page(height: 60pt, margin: 0pt)
+#set image(height: 100%)
+#image("/assets/images/tiger.jpg", filter: "grayscale")
+#image("/assets/images/rhino.png", filter: "sepia")
+
+--- image-filter-vector paged ---
+// Error: 2-53 image filters are only supported for raster images
+#image("/assets/images/monkey.svg", filter: "sepia")
+
 --- image-jump-to-next-page paged ---
 // Does not fit to remaining height of page.
 #set page(height: 60pt)
@@ -303,6 +347,10 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Error: 2-37 unknown image format
 #image("/assets/plugins/hello.wasm")
 
+--- image-jpeg-xl paged ---
+// Error: 2-28 JPEG XL images are not supported yet
+#image(bytes((0xff, 0x0a)))
+
 --- image-bad-svg paged ---
 // Error: "/assets/images/bad.svg" 4:3 failed to parse SVG (found closing tag 'g' instead of 'style')
 #image("/assets/images/bad.svg")
@@ -375,7 +423,7 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 --- image-png-but-pixmap-format eval ---
 #image(
   read("/assets/images/tiger.jpg", encoding: none),
-  // Error: 11-18 expected "png", "jpg", "gif", "webp", dictionary, "svg", "pdf", or auto
+  // Error: 11-18 expected "png", "jpg", "gif", "webp", "tiff", dictionary, "svg", "pdf", or auto
   format: "rgba8",
 )
 
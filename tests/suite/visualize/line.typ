@@ -95,3 +95,28 @@
 --- line-infinite-length paged ---
 // Error: 2-54 cannot create line with infinite length
 #line(start: (0pt, 0pt), end: (float.inf * 1pt, 0pt))
+
+--- line-markers paged ---
+#set page(width: 120pt, height: auto)
+#set line(length: 100%)
+#stack(
+  spacing: 8pt,
+  line(end-marker: "arrow"),
+  line(start-marker: "arrow", end-marker: "arrow", stroke: 2pt + blue),
+  line(start-marker: "circle", end-marker: "bar"),
+  line(end-marker: (kind: "chevron", size: 10pt), stroke: (thickness: 2pt, join: "round")),
+  line(end-marker: (kind: "chevron", size: 10pt), stroke: (thickness: 2pt, join: "miter")),
+)
+
+--- line-markers-angled paged ---
+#set page(width: auto, height: auto, margin: 10pt)
+#box(line(end: (40pt, 30pt), start-marker: "bar", end-marker: "arrow"))
+#box(line(length: 40pt, angle: -45deg, end-marker: "chevron"))
+
+--- line-marker-bad-key eval ---
+// Error: 19-46 unexpected key "sizes", valid keys are "kind" and "size"
+#line(end-marker: (kind: "arrow", sizes: 2pt))
+
+--- line-marker-bad-kind eval ---
+// Error: 19-25 expected "arrow", "chevron", "circle", "bar", dictionary, or none
+#line(end-marker: "star")
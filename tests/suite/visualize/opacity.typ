@@ -0,0 +1,27 @@
+// Test translucent content.
+
+--- opacity paged ---
+#set page(width: 120pt, height: auto)
+#opacity(50%)[Half transparent text.]
+#opacity(30%, rect(fill: blue, width: 100%, height: 10pt))
+
+--- opacity-overlap paged ---
+// Overlapping parts of the content don't shine through each other.
+#set page(width: auto, height: auto, margin: 5pt)
+#opacity(50%, box(width: 40pt, height: 30pt, {
+  place(rect(width: 30pt, height: 30pt, fill: red))
+  place(dx: 10pt, rect(width: 30pt, height: 30pt, fill: blue))
+}))
+
+--- opacity-watermark paged ---
+#set page(width: 100pt, height: 60pt)
+#place(center + horizon, opacity(20%, text(24pt, red)[DRAFT]))
+This document is not final.
+
+--- opacity-invalid paged ---
+// Error: 10-14 opacity must be between 0% and 100%
+#opacity(150%)[A]
+
+--- opacity-invalid-set eval ---
+// Error: 14-18 opacity must be between 0% and 100%
+#set opacity(150%)
@@ -0,0 +1,25 @@
+// Test shadows behind shapes and boxes.
+
+--- shadow-rect paged ---
+#set page(width: 120pt, height: auto, margin: 10pt)
+#rect(fill: white, stroke: 0.5pt, shadow: (:))[Default shadow]
+#rect(fill: white, radius: 5pt, shadow: (dx: 0pt, dy: 4pt, blur: 8pt))[Rounded]
+#rect(fill: white, shadow: (blur: 0pt, color: blue))[Hard shadow]
+
+--- shadow-round paged ---
+#set page(width: auto, height: auto, margin: 10pt)
+#stack(
+  dir: ltr,
+  spacing: 10pt,
+  circle(radius: 15pt, fill: yellow, shadow: (:)),
+  ellipse(width: 40pt, height: 20pt, fill: white, shadow: red.transparentize(50%)),
+  square(size: 30pt, fill: white, outset: 2pt, shadow: (dx: -3pt, dy: 3pt)),
+)
+
+--- shadow-box paged ---
+#set page(width: 120pt, height: auto, margin: 10pt)
+Some #box(fill: white, inset: 3pt, radius: 2pt, shadow: (dy: 1pt, blur: 3pt))[inline] text.
+
+--- shadow-bad-key eval ---
+// Error: 15-35 unexpected key "offset", valid keys are "dx", "dy", "blur", and "color"
+#rect(shadow: (offset: (1pt, 2pt)))